@@ -1,17 +1,64 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum NumberScript {
     SimplifiedChinese(ScriptStyle),
     TraditionalChinese(ScriptStyle),
     Japanese(ScriptStyle),
     Korean(ScriptStyle),
+    /// 越南的漢字（Hán），也就是漢越音讀的數字，寫法與繁體中文相同，例如 **「一百二十三」**、**「壹佰貳拾參」**。
+    ///
+    /// 不包含喃字（Nôm）的數字，例如 **「𠬠」**、**「𠄩」**、**「𠀧」**。越南語國語字的讀法請見 `NumberToQuocNgu`，它只有一種寫法，與 `ScriptStyle` 無關。
+    Vietnamese(ScriptStyle),
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum ScriptStyle {
+    /// 大寫。日文使用法律上規定的大字 **「壱弐参拾」**，其他數字與單位和小寫相同。
     Upper,
+    /// 日文舊式文書的大字，除了 **「壱弐参拾」** 以外，也使用 **「肆伍陸漆捌玖陌阡萬」**。其他語言與 `Upper` 相同。
+    HistoricUpper,
     Lower { circle_as_zero: bool },
 }
+
+impl NumberScript {
+    /// 取得書寫樣式。
+    #[inline]
+    pub const fn style(self) -> ScriptStyle {
+        match self {
+            Self::SimplifiedChinese(ss)
+            | Self::TraditionalChinese(ss)
+            | Self::Japanese(ss)
+            | Self::Korean(ss)
+            | Self::Vietnamese(ss) => ss,
+        }
+    }
+
+    /// 保留語言，換成另一種書寫樣式。
+    #[inline]
+    pub const fn with_style(self, style: ScriptStyle) -> Self {
+        match self {
+            Self::SimplifiedChinese(_) => Self::SimplifiedChinese(style),
+            Self::TraditionalChinese(_) => Self::TraditionalChinese(style),
+            Self::Japanese(_) => Self::Japanese(style),
+            Self::Korean(_) => Self::Korean(style),
+            Self::Vietnamese(_) => Self::Vietnamese(style),
+        }
+    }
+
+    /// 只有日文區分大字的等級，其他語言的 `HistoricUpper` 換成 `Upper`。
+    #[inline]
+    pub(crate) const fn normalize_upper(self) -> Self {
+        match self {
+            Self::Japanese(_) => self,
+            _ => match self.style() {
+                ScriptStyle::HistoricUpper => self.with_style(ScriptStyle::Upper),
+                _ => self,
+            },
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use enum_ordinalize::Ordinalize;
 
-use crate::{NumberScript, ScriptStyle};
+use crate::{ChineseCountMethod, NumberScript, ScriptStyle};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ordinalize)]
 #[ordinalize(impl_trait = false)]
@@ -25,84 +25,149 @@ pub(crate) enum ChineseNumber {
 impl ChineseNumber {
     #[inline]
     pub(crate) const fn to_str(self, number_script: NumberScript) -> &'static str {
+        let number_script = number_script.normalize_upper();
+
         match self {
             Self::零 => match number_script {
                 NumberScript::SimplifiedChinese(ss)
                 | NumberScript::TraditionalChinese(ss)
-                | NumberScript::Japanese(ss) => match ss {
+                | NumberScript::Japanese(ss)
+                | NumberScript::Vietnamese(ss) => match ss {
                     ScriptStyle::Lower {
                         circle_as_zero: true,
                     } => "〇",
                     _ => "零",
                 },
+                NumberScript::Korean(ScriptStyle::Upper) => "零",
                 NumberScript::Korean(_) => "영",
             },
             Self::一 => match number_script {
                 NumberScript::SimplifiedChinese(ScriptStyle::Upper)
-                | NumberScript::TraditionalChinese(ScriptStyle::Upper) => "壹",
-                NumberScript::Japanese(ScriptStyle::Upper) => "壱",
+                | NumberScript::TraditionalChinese(ScriptStyle::Upper)
+                | NumberScript::Korean(ScriptStyle::Upper)
+                | NumberScript::Vietnamese(ScriptStyle::Upper) => "壹",
+                NumberScript::Japanese(ScriptStyle::Upper | ScriptStyle::HistoricUpper) => "壱",
                 NumberScript::Korean(_) => "일",
                 _ => "一",
             },
             Self::二 => match number_script {
                 NumberScript::SimplifiedChinese(ScriptStyle::Upper)  => "贰",
-                NumberScript::TraditionalChinese(ScriptStyle::Upper) => "貳",
-                NumberScript::Japanese(ScriptStyle::Upper) => "弐",
+                NumberScript::TraditionalChinese(ScriptStyle::Upper)
+                | NumberScript::Korean(ScriptStyle::Upper)
+                | NumberScript::Vietnamese(ScriptStyle::Upper) => "貳",
+                NumberScript::Japanese(ScriptStyle::Upper | ScriptStyle::HistoricUpper) => "弐",
                 NumberScript::Korean(_) => "이",
                 _ => "二",
             },
             Self::三 => match number_script {
                 NumberScript::SimplifiedChinese(ScriptStyle::Upper) => "叁",
-                NumberScript::TraditionalChinese(ScriptStyle::Upper) => "參",
-                NumberScript::Japanese(ScriptStyle::Upper) => "参",
+                NumberScript::TraditionalChinese(ScriptStyle::Upper)
+                | NumberScript::Korean(ScriptStyle::Upper)
+                | NumberScript::Vietnamese(ScriptStyle::Upper) => "參",
+                NumberScript::Japanese(ScriptStyle::Upper | ScriptStyle::HistoricUpper) => "参",
                 NumberScript::Korean(_) => "삼",
                 _ => "三",
             },
             Self::四 => match number_script {
-                NumberScript::SimplifiedChinese(ScriptStyle::Upper) => "肆",
-                NumberScript::TraditionalChinese(ScriptStyle::Upper) => "肆",
+                NumberScript::Japanese(ScriptStyle::HistoricUpper) => "肆",
+                NumberScript::SimplifiedChinese(ScriptStyle::Upper)
+                | NumberScript::TraditionalChinese(ScriptStyle::Upper)
+                | NumberScript::Vietnamese(ScriptStyle::Upper) => "肆",
+                NumberScript::Korean(ScriptStyle::Upper) => "四",
                 NumberScript::Korean(_) => "사",
                 _ => "四",
             },
             Self::五 => match number_script {
-                NumberScript::SimplifiedChinese(ScriptStyle::Upper) => "伍",
-                NumberScript::TraditionalChinese(ScriptStyle::Upper) => "伍",
+                NumberScript::Japanese(ScriptStyle::HistoricUpper) => "伍",
+                NumberScript::SimplifiedChinese(ScriptStyle::Upper)
+                | NumberScript::TraditionalChinese(ScriptStyle::Upper)
+                | NumberScript::Vietnamese(ScriptStyle::Upper) => "伍",
+                NumberScript::Korean(ScriptStyle::Upper) => "五",
                 NumberScript::Korean(_) => "오",
                 _ => "五",
             },
             Self::六 => match number_script {
+                NumberScript::Japanese(ScriptStyle::HistoricUpper) => "陸",
                 NumberScript::SimplifiedChinese(ScriptStyle::Upper) => "陆",
-                NumberScript::TraditionalChinese(ScriptStyle::Upper) => "陸",
+                NumberScript::TraditionalChinese(ScriptStyle::Upper)
+                | NumberScript::Vietnamese(ScriptStyle::Upper) => "陸",
+                NumberScript::Korean(ScriptStyle::Upper) => "六",
                 NumberScript::Korean(_) => "육",
                 _ => "六",
             },
             Self::七 => match number_script {
+                NumberScript::Japanese(ScriptStyle::HistoricUpper) => "漆",
                 NumberScript::SimplifiedChinese(ScriptStyle::Upper)
-                | NumberScript::TraditionalChinese(ScriptStyle::Upper) => "柒",
+                | NumberScript::TraditionalChinese(ScriptStyle::Upper)
+                | NumberScript::Vietnamese(ScriptStyle::Upper) => "柒",
+                NumberScript::Korean(ScriptStyle::Upper) => "七",
                 NumberScript::Korean(_) => "칠",
                 _ => "七",
             },
             Self::八 => match number_script {
+                NumberScript::Japanese(ScriptStyle::HistoricUpper) => "捌",
                 NumberScript::SimplifiedChinese(ScriptStyle::Upper)
-                | NumberScript::TraditionalChinese(ScriptStyle::Upper) => "捌",
+                | NumberScript::TraditionalChinese(ScriptStyle::Upper)
+                | NumberScript::Vietnamese(ScriptStyle::Upper) => "捌",
+                NumberScript::Korean(ScriptStyle::Upper) => "八",
                 NumberScript::Korean(_) => "팔",
                 _ => "八",
             },
             Self::九 => match number_script {
+                NumberScript::Japanese(ScriptStyle::HistoricUpper) => "玖",
                 NumberScript::SimplifiedChinese(ScriptStyle::Upper)
-                | NumberScript::TraditionalChinese(ScriptStyle::Upper) => "玖",
+                | NumberScript::TraditionalChinese(ScriptStyle::Upper)
+                | NumberScript::Vietnamese(ScriptStyle::Upper) => "玖",
+                NumberScript::Korean(ScriptStyle::Upper) => "九",
                 NumberScript::Korean(_) => "구",
                 _ => "九",
             },
             Self::十 => match number_script {
                 NumberScript::SimplifiedChinese(ScriptStyle::Upper)
                 | NumberScript::TraditionalChinese(ScriptStyle::Upper)
-                | NumberScript::Japanese(ScriptStyle::Upper) => "拾",
+                | NumberScript::Japanese(ScriptStyle::Upper | ScriptStyle::HistoricUpper)
+                | NumberScript::Korean(ScriptStyle::Upper)
+                | NumberScript::Vietnamese(ScriptStyle::Upper) => "拾",
                 NumberScript::Korean(_) => "십",
                 _ => "十",
             },
         }
     }
+
+    /// 日文的平假名讀音。
+    #[inline]
+    pub(crate) const fn to_kana(self) -> &'static str {
+        match self {
+            Self::零 => "ぜろ",
+            Self::一 => "いち",
+            Self::二 => "に",
+            Self::三 => "さん",
+            Self::四 => "よん",
+            Self::五 => "ご",
+            Self::六 => "ろく",
+            Self::七 => "なな",
+            Self::八 => "はち",
+            Self::九 => "きゅう",
+            Self::十 => "じゅう",
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn from_char(character: char) -> Option<Self> {
+        match character {
+            '零' | '〇' | '영' => Some(Self::零),
+            '一' | '壹' | '壱' | '일' => Some(Self::一),
+            '二' | '貳' | '贰' | '弐' | '兩' | '两' | '이' => Some(Self::二),
+            '三' | '參' | '叁' | '参' | '삼' => Some(Self::三),
+            '四' | '肆' | '사' => Some(Self::四),
+            '五' | '伍' | '오' => Some(Self::五),
+            '六' | '陸' | '陆' | '육' => Some(Self::六),
+            '七' | '柒' | '漆' | '칠' => Some(Self::七),
+            '八' | '捌' | '팔' => Some(Self::八),
+            '九' | '玖' | '구' => Some(Self::九),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Ordinalize)]
@@ -135,96 +200,206 @@ pub(crate) enum ChineseExponent {
 impl ChineseExponent {
     #[inline]
     pub(crate) const fn to_str(self, number_script: NumberScript) -> &'static str {
+        let number_script = number_script.normalize_upper();
+
         match self {
             Self::分 => match number_script {
-                NumberScript::SimplifiedChinese(_) | NumberScript::TraditionalChinese(_) => "分",
+                NumberScript::SimplifiedChinese(_)
+                | NumberScript::TraditionalChinese(_)
+                | NumberScript::Vietnamese(_) => "分",
                 NumberScript::Japanese(_) => "厘",
+                NumberScript::Korean(ScriptStyle::Upper) => "分",
                 NumberScript::Korean(_) => "분",
             },
             Self::角 => match number_script {
-                NumberScript::SimplifiedChinese(_) | NumberScript::TraditionalChinese(_) => "角",
+                NumberScript::SimplifiedChinese(_)
+                | NumberScript::TraditionalChinese(_)
+                | NumberScript::Vietnamese(_) => "角",
                 NumberScript::Japanese(_) => "钱",
+                NumberScript::Korean(ScriptStyle::Upper) => "角",
                 NumberScript::Korean(_) => "각",
             },
             Self::個 => match number_script {
                 NumberScript::SimplifiedChinese(_) => "个",
-                NumberScript::TraditionalChinese(_) | NumberScript::Japanese(_) => "個",
+                NumberScript::TraditionalChinese(_)
+                | NumberScript::Japanese(_)
+                | NumberScript::Vietnamese(_) => "個",
+                NumberScript::Korean(ScriptStyle::Upper) => "個",
                 NumberScript::Korean(_) => "개",
             },
             Self::十 => match number_script {
                 NumberScript::SimplifiedChinese(ScriptStyle::Upper)
-                | NumberScript::TraditionalChinese(ScriptStyle::Upper) => "拾",
+                | NumberScript::TraditionalChinese(ScriptStyle::Upper)
+                | NumberScript::Japanese(ScriptStyle::Upper | ScriptStyle::HistoricUpper)
+                | NumberScript::Korean(ScriptStyle::Upper)
+                | NumberScript::Vietnamese(ScriptStyle::Upper) => "拾",
                 NumberScript::Korean(_) => "십",
                 _ => "十",
             },
             Self::百 => match number_script {
+                NumberScript::Japanese(ScriptStyle::HistoricUpper) => "陌",
                 NumberScript::SimplifiedChinese(ScriptStyle::Upper)
-                | NumberScript::TraditionalChinese(ScriptStyle::Upper) => "佰",
+                | NumberScript::TraditionalChinese(ScriptStyle::Upper)
+                | NumberScript::Vietnamese(ScriptStyle::Upper) => "佰",
+                NumberScript::Korean(ScriptStyle::Upper) => "百",
                 NumberScript::Korean(_) => "백",
                 _ => "百",
             },
             Self::千 => match number_script {
+                NumberScript::Japanese(ScriptStyle::HistoricUpper) => "阡",
                 NumberScript::SimplifiedChinese(ScriptStyle::Upper)
-                | NumberScript::TraditionalChinese(ScriptStyle::Upper) => "仟",
+                | NumberScript::TraditionalChinese(ScriptStyle::Upper)
+                | NumberScript::Vietnamese(ScriptStyle::Upper) => "仟",
+                NumberScript::Korean(ScriptStyle::Upper) => "千",
                 NumberScript::Korean(_) => "천",
                 _ => "千",
             },
             Self::萬 => match number_script {
-                NumberScript::TraditionalChinese(_) => "萬",
+                NumberScript::TraditionalChinese(_)
+                | NumberScript::Japanese(ScriptStyle::HistoricUpper)
+                | NumberScript::Vietnamese(_) => "萬",
+                NumberScript::Korean(ScriptStyle::Upper) => "萬",
                 NumberScript::Korean(_) => "만",
                 _ => "万",
             },
             Self::億 => match number_script {
                 NumberScript::SimplifiedChinese(_) => "亿",
+                NumberScript::Korean(ScriptStyle::Upper) => "億",
                 NumberScript::Korean(_) => "억",
                 _ => "億",
             },
             Self::兆 => match number_script {
+                NumberScript::Korean(ScriptStyle::Upper) => "兆",
                 NumberScript::Korean(_) => "조",
                 _ => "兆",
             },
             Self::京 => match number_script {
+                NumberScript::Korean(ScriptStyle::Upper) => "京",
                 NumberScript::Korean(_) => "경",
                 _ => "京",
             },
             Self::垓 => match number_script {
+                NumberScript::Korean(ScriptStyle::Upper) => "垓",
                 NumberScript::Korean(_) => "해",
                 _ => "垓",
             },
             Self::秭 => match number_script {
+                NumberScript::Korean(ScriptStyle::Upper) => "秭",
                 NumberScript::Korean(_) => "자",
                 _ => "秭",
             },
             Self::穰 => match number_script {
+                NumberScript::Korean(ScriptStyle::Upper) => "穰",
                 NumberScript::Korean(_) => "양",
                 _ => "穰",
             },
             Self::溝 => match number_script {
                 NumberScript::SimplifiedChinese(_) => "沟",
+                NumberScript::Korean(ScriptStyle::Upper) => "溝",
                 NumberScript::Korean(_) => "구",
                 _ => "溝",
             },
             Self::澗 => match number_script {
                 NumberScript::SimplifiedChinese(_) => "涧",
+                NumberScript::Korean(ScriptStyle::Upper) => "澗",
                 NumberScript::Korean(_) => "간",
                 _ => "澗",
             },
             Self::正 => match number_script {
+                NumberScript::Korean(ScriptStyle::Upper) => "正",
                 NumberScript::Korean(_) => "정",
                 _ => "正",
             },
             Self::載 => match number_script {
                 NumberScript::SimplifiedChinese(_) => "载",
+                NumberScript::Korean(ScriptStyle::Upper) => "載",
                 NumberScript::Korean(_) => "재",
                 _ => "載",
             },
             Self::極 => match number_script {
                 NumberScript::SimplifiedChinese(_) => "极",
+                NumberScript::Korean(ScriptStyle::Upper) => "極",
                 NumberScript::Korean(_) => "극",
                 _ => "極",
             },
         }
     }
+
+    /// 取得單位在指定算術類型下代表的十的次方。
+    pub(crate) const fn power(self, method: ChineseCountMethod) -> u32 {
+        match self {
+            Self::分 | Self::角 | Self::個 => 0,
+            Self::十 => 1,
+            Self::百 => 2,
+            Self::千 => 3,
+            _ => {
+                let k = (self as u8 - Self::萬 as u8) as u32;
+
+                match method {
+                    ChineseCountMethod::Low => 4 + k,
+                    ChineseCountMethod::TenThousand => 4 * (k + 1),
+                    ChineseCountMethod::Middle => {
+                        if k == 0 {
+                            4
+                        } else {
+                            8 * k
+                        }
+                    },
+                    ChineseCountMethod::High => 4 << k,
+                }
+            },
+        }
+    }
+
+    /// 日文的平假名讀音。
+    #[inline]
+    pub(crate) const fn to_kana(self) -> &'static str {
+        match self {
+            Self::分 => "りん",
+            Self::角 => "せん",
+            Self::個 => "こ",
+            Self::十 => "じゅう",
+            Self::百 => "ひゃく",
+            Self::千 => "せん",
+            Self::萬 => "まん",
+            Self::億 => "おく",
+            Self::兆 => "ちょう",
+            Self::京 => "けい",
+            Self::垓 => "がい",
+            Self::秭 => "じょ",
+            Self::穰 => "じょう",
+            Self::溝 => "こう",
+            Self::澗 => "かん",
+            Self::正 => "せい",
+            Self::載 => "さい",
+            Self::極 => "ごく",
+        }
+    }
+
+    /// 韓文的 `구` 同時是數字 `九` 與單位 `溝`，需由呼叫者依上下文判斷。
+    #[inline]
+    pub(crate) const fn from_char(character: char) -> Option<Self> {
+        match character {
+            '分' | '厘' | '분' => Some(Self::分),
+            '角' | '钱' | '銭' | '각' => Some(Self::角),
+            '十' | '拾' | '십' => Some(Self::十),
+            '百' | '佰' | '陌' | '백' => Some(Self::百),
+            '千' | '仟' | '阡' | '천' => Some(Self::千),
+            '萬' | '万' | '만' => Some(Self::萬),
+            '億' | '亿' | '억' => Some(Self::億),
+            '兆' | '조' => Some(Self::兆),
+            '京' | '경' => Some(Self::京),
+            '垓' | '해' => Some(Self::垓),
+            '秭' | '자' => Some(Self::秭),
+            '穰' | '양' => Some(Self::穰),
+            '溝' | '沟' | '구' => Some(Self::溝),
+            '澗' | '涧' | '간' => Some(Self::澗),
+            '正' | '정' => Some(Self::正),
+            '載' | '载' | '재' => Some(Self::載),
+            '極' | '极' | '극' => Some(Self::極),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -238,18 +413,31 @@ pub(crate) enum ChineseSign {
 impl ChineseSign {
     #[inline]
     pub(crate) const fn to_str(self, number_script: NumberScript) -> &'static str {
+        let number_script = number_script.normalize_upper();
+
         match self {
             Self::正 => match number_script {
+                NumberScript::Korean(ScriptStyle::Upper) => "正",
                 NumberScript::Korean(_) => "정",
                 _ => "正",
             },
             Self::負 => match number_script {
                 NumberScript::SimplifiedChinese(_) => "负",
+                NumberScript::Korean(ScriptStyle::Upper) => "負",
                 NumberScript::Korean(_) => "부",
                 _ => "負",
             },
         }
     }
+
+    #[inline]
+    pub(crate) const fn from_char(character: char) -> Option<Self> {
+        match character {
+            '正' | '정' => Some(Self::正),
+            '負' | '负' | '부' => Some(Self::負),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -258,10 +446,155 @@ pub(crate) struct ChinesePoint;
 impl ChinesePoint {
     #[inline]
     pub(crate) const fn to_str(number_script: NumberScript) -> &'static str {
-        match number_script {
-            NumberScript::TraditionalChinese(_) => "點",
+        match number_script.normalize_upper() {
+            NumberScript::TraditionalChinese(_)
+            | NumberScript::Korean(ScriptStyle::Upper)
+            | NumberScript::Vietnamese(_) => "點",
             NumberScript::Korean(_) => "점",
             _ => "点",
         }
     }
+
+    #[inline]
+    pub(crate) const fn from_char(character: char) -> Option<Self> {
+        match character {
+            '點' | '点' | '점' => Some(Self),
+            _ => None,
+        }
+    }
+}
+
+/// 檢查字元是否為指定 `NumberScript` 在該書寫樣式下會使用的字元。
+pub(crate) fn is_char_of_number_script(number_script: NumberScript, character: char) -> bool {
+    let is = |s: &str| s.starts_with(character);
+
+    (0..=ChineseNumber::十.ordinal())
+        .any(|i| is(unsafe { ChineseNumber::from_ordinal_unsafe(i) }.to_str(number_script)))
+        || (0..=ChineseExponent::極.ordinal())
+            .any(|i| is(unsafe { ChineseExponent::from_ordinal_unsafe(i) }.to_str(number_script)))
+        || is(ChineseSign::正.to_str(number_script))
+        || is(ChineseSign::負.to_str(number_script))
+        || is(ChinesePoint::to_str(number_script))
+        // 口語中的「兩」
+        || matches!(
+            (number_script, character),
+            (NumberScript::SimplifiedChinese(_), '两') | (NumberScript::TraditionalChinese(_), '兩')
+        )
+}
+
+/// 傳統的小數單位，每一級為前一級的十分之一。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Ordinalize)]
+#[ordinalize(impl_trait = false)]
+#[ordinalize(ordinal(pub(crate) fn ordinal))]
+#[ordinalize(from_ordinal_unsafe(pub(crate) fn from_ordinal_unsafe))]
+#[allow(dead_code)]
+#[repr(u8)]
+pub(crate) enum ChineseFractionUnit {
+    分,
+    釐,
+    毫,
+    絲,
+    忽,
+    微,
+    纖,
+    沙,
+    塵,
+    埃,
+    渺,
+    漠,
+}
+
+impl ChineseFractionUnit {
+    #[inline]
+    pub(crate) const fn to_str(self, number_script: NumberScript) -> &'static str {
+        let number_script = number_script.normalize_upper();
+
+        match self {
+            Self::分 => match number_script {
+                NumberScript::Korean(ScriptStyle::Upper) => "分",
+                NumberScript::Korean(_) => "분",
+                _ => "分",
+            },
+            Self::釐 => match number_script {
+                NumberScript::TraditionalChinese(_) | NumberScript::Vietnamese(_) => "釐",
+                NumberScript::Korean(ScriptStyle::Upper) => "釐",
+                NumberScript::Korean(_) => "리",
+                _ => "厘",
+            },
+            Self::毫 => match number_script {
+                NumberScript::Japanese(_) => "毛",
+                NumberScript::Korean(ScriptStyle::Upper) => "毫",
+                NumberScript::Korean(_) => "모",
+                _ => "毫",
+            },
+            Self::絲 => match number_script {
+                NumberScript::SimplifiedChinese(_) => "丝",
+                NumberScript::TraditionalChinese(_) | NumberScript::Vietnamese(_) => "絲",
+                NumberScript::Japanese(_) => "糸",
+                NumberScript::Korean(ScriptStyle::Upper) => "絲",
+                NumberScript::Korean(_) => "사",
+            },
+            Self::忽 => match number_script {
+                NumberScript::Korean(ScriptStyle::Upper) => "忽",
+                NumberScript::Korean(_) => "홀",
+                _ => "忽",
+            },
+            Self::微 => match number_script {
+                NumberScript::Korean(ScriptStyle::Upper) => "微",
+                NumberScript::Korean(_) => "미",
+                _ => "微",
+            },
+            Self::纖 => match number_script {
+                NumberScript::SimplifiedChinese(_) => "纤",
+                NumberScript::TraditionalChinese(_) | NumberScript::Vietnamese(_) => "纖",
+                NumberScript::Japanese(_) => "繊",
+                NumberScript::Korean(ScriptStyle::Upper) => "纖",
+                NumberScript::Korean(_) => "섬",
+            },
+            Self::沙 => match number_script {
+                NumberScript::Korean(ScriptStyle::Upper) => "沙",
+                NumberScript::Korean(_) => "사",
+                _ => "沙",
+            },
+            Self::塵 => match number_script {
+                NumberScript::SimplifiedChinese(_) => "尘",
+                NumberScript::Korean(ScriptStyle::Upper) => "塵",
+                NumberScript::Korean(_) => "진",
+                _ => "塵",
+            },
+            Self::埃 => match number_script {
+                NumberScript::Korean(ScriptStyle::Upper) => "埃",
+                NumberScript::Korean(_) => "애",
+                _ => "埃",
+            },
+            Self::渺 => match number_script {
+                NumberScript::Korean(ScriptStyle::Upper) => "渺",
+                NumberScript::Korean(_) => "묘",
+                _ => "渺",
+            },
+            Self::漠 => match number_script {
+                NumberScript::Korean(ScriptStyle::Upper) => "漠",
+                NumberScript::Korean(_) => "막",
+                _ => "漠",
+            },
+        }
+    }
+    /// 日文的平假名讀音。
+    #[inline]
+    pub(crate) const fn to_kana(self) -> &'static str {
+        match self {
+            Self::分 => "ぶ",
+            Self::釐 => "りん",
+            Self::毫 => "もう",
+            Self::絲 => "し",
+            Self::忽 => "こつ",
+            Self::微 => "び",
+            Self::纖 => "せん",
+            Self::沙 => "しゃ",
+            Self::塵 => "じん",
+            Self::埃 => "あい",
+            Self::渺 => "びょう",
+            Self::漠 => "ばく",
+        }
+    }
 }
@@ -0,0 +1,27 @@
+use core::fmt::{self, Display, Formatter};
+use std::error::Error;
+
+/// 將中文數字轉成數值時發生的錯誤。
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ChineseToNumberError {
+    ChineseNumberEmpty,
+    ChineseNumberIncorrect { char_index: usize },
+    Overflow,
+    Underflow,
+}
+
+impl Display for ChineseToNumberError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            ChineseToNumberError::ChineseNumberEmpty => f.write_str("chinese number is empty"),
+            ChineseToNumberError::ChineseNumberIncorrect {
+                char_index,
+            } => write!(f, "chinese number is incorrect (position: {char_index})"),
+            ChineseToNumberError::Overflow => f.write_str("number is too large"),
+            ChineseToNumberError::Underflow => f.write_str("number is too small"),
+        }
+    }
+}
+
+impl Error for ChineseToNumberError {}
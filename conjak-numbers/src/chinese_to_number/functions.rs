@@ -0,0 +1,445 @@
+use alloc::{string::String, vec::Vec};
+use core::{fmt::Write, str::FromStr};
+
+use num_bigint::{BigInt, BigUint, Sign};
+#[allow(unused_imports)]
+use num_traits::float::FloatCore;
+use num_traits::{One, Zero};
+
+use super::is_char_of_any_style;
+use crate::{
+    ChineseCountMethod, ChineseExponent, ChineseNumber, ChinesePoint, ChineseSign,
+    ChineseToNumberError, Decimal, NumberScript,
+};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum ChineseToken {
+    Number(ChineseNumber),
+    Exponent(ChineseExponent),
+    Point,
+}
+
+/// 記號與其在原字串中的字元位置。
+pub(crate) type ChineseTokens = [(usize, ChineseToken)];
+
+/// 解析完成的中文數字。小數部分以十進位的數字陣列保存，並去掉結尾的零。
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ChineseNumberParts {
+    pub(crate) negative: bool,
+    pub(crate) integer: BigUint,
+    pub(crate) fraction: Vec<u8>,
+    pub(crate) fraction_index: usize,
+}
+
+impl ChineseNumberParts {
+    pub(crate) fn to_integer<T>(&self) -> Result<T, ChineseToNumberError>
+    where
+        T: for<'a> TryFrom<&'a BigInt>, {
+        if !self.fraction.is_empty() {
+            return Err(ChineseToNumberError::ChineseNumberIncorrect {
+                char_index: self.fraction_index,
+            });
+        }
+
+        let sign = if self.negative { Sign::Minus } else { Sign::Plus };
+        let value = BigInt::from_biguint(sign, self.integer.clone());
+
+        T::try_from(&value).map_err(|_| {
+            if self.negative {
+                ChineseToNumberError::Underflow
+            } else {
+                ChineseToNumberError::Overflow
+            }
+        })
+    }
+
+    #[inline]
+    pub(crate) fn into_decimal(self) -> Decimal {
+        Decimal::new(self.negative, self.integer, self.fraction)
+    }
+
+    pub(crate) fn to_float<T>(&self) -> Result<T, ChineseToNumberError>
+    where
+        T: FloatCore + FromStr, {
+        let mut s = String::new();
+
+        if self.negative {
+            s.push('-');
+        }
+
+        write!(s, "{}", self.integer).unwrap();
+
+        if !self.fraction.is_empty() {
+            s.push('.');
+
+            for d in self.fraction.iter() {
+                s.push((b'0' + d) as char);
+            }
+        }
+
+        let value: T = s.parse().map_err(|_| ChineseToNumberError::Overflow)?;
+
+        if value.is_infinite() {
+            if self.negative {
+                Err(ChineseToNumberError::Underflow)
+            } else {
+                Err(ChineseToNumberError::Overflow)
+            }
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+/// 能從解析結果取得的數值型別。這個 trait 位於私有模組中，外部無法實作。
+pub trait FromChineseNumberParts: Sized {
+    fn from_chinese_number_parts(parts: ChineseNumberParts) -> Result<Self, ChineseToNumberError>;
+}
+
+macro_rules! impl_from_parts_for_integer {
+    ($($t:ty),* $(,)*) => {
+        $(
+            impl FromChineseNumberParts for $t {
+                #[inline]
+                fn from_chinese_number_parts(parts: ChineseNumberParts) -> Result<Self, ChineseToNumberError> {
+                    parts.to_integer()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_parts_for_integer!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
+
+impl FromChineseNumberParts for f32 {
+    #[inline]
+    fn from_chinese_number_parts(parts: ChineseNumberParts) -> Result<Self, ChineseToNumberError> {
+        parts.to_float()
+    }
+}
+
+impl FromChineseNumberParts for f64 {
+    #[inline]
+    fn from_chinese_number_parts(parts: ChineseNumberParts) -> Result<Self, ChineseToNumberError> {
+        parts.to_float()
+    }
+}
+
+impl FromChineseNumberParts for Decimal {
+    #[inline]
+    fn from_chinese_number_parts(parts: ChineseNumberParts) -> Result<Self, ChineseToNumberError> {
+        Ok(parts.into_decimal())
+    }
+}
+
+#[inline]
+fn incorrect(char_index: usize) -> ChineseToNumberError {
+    ChineseToNumberError::ChineseNumberIncorrect {
+        char_index,
+    }
+}
+
+/// 將字串切成符號與記號。空白字元會被忽略。指定 `number_script` 時，不屬於該語言的字元會被視為錯誤。
+pub(crate) fn tokenize(
+    chinese_number: &str,
+    naive: bool,
+    number_script: Option<NumberScript>,
+) -> Result<(bool, Vec<(usize, ChineseToken)>), ChineseToNumberError> {
+    let mut negative = false;
+    let mut signed = false;
+    let mut tokens: Vec<(usize, ChineseToken)> = Vec::new();
+
+    for (i, c) in chinese_number.chars().enumerate() {
+        if c.is_whitespace() {
+            continue;
+        }
+
+        if number_script.is_some_and(|number_script| !is_char_of_any_style(number_script, c)) {
+            return Err(incorrect(i));
+        }
+
+        if tokens.is_empty()
+            && !signed
+            && let Some(sign) = ChineseSign::from_char(c)
+        {
+            negative = sign == ChineseSign::負;
+            signed = true;
+
+            continue;
+        }
+
+        // 整數部分的數字不會連續出現，因此韓文的 `구` 緊接在數字之後時只能是單位 `溝`；小數部分的 `구` 一律是數字
+        let after_integer_digit = !naive
+            && matches!(number_script, None | Some(NumberScript::Korean(_)))
+            && !tokens.iter().any(|(_, t)| *t == ChineseToken::Point)
+            && matches!(
+                tokens.last(),
+                Some((_, ChineseToken::Number(n))) if *n != ChineseNumber::零
+            );
+
+        let token = if ChinesePoint::from_char(c).is_some() {
+            ChineseToken::Point
+        } else if c == '구' && after_integer_digit {
+            ChineseToken::Exponent(ChineseExponent::溝)
+        } else if let Some(n) = ChineseNumber::from_char(c) {
+            ChineseToken::Number(n)
+        } else if let (false, Some(e)) = (naive, ChineseExponent::from_char(c)) {
+            ChineseToken::Exponent(e)
+        } else {
+            return Err(incorrect(i));
+        };
+
+        tokens.push((i, token));
+    }
+
+    if tokens.is_empty() {
+        return Err(ChineseToNumberError::ChineseNumberEmpty);
+    }
+
+    Ok((negative, tokens))
+}
+
+/// 解析帶有單位的整數部分。
+///
+/// 大的單位會把前面所有比它小的段落收進來再相乘，緊接在較小單位之後時則與它組成複合的單位，因此 **「一萬億」**、**「十二萬三千京」** 這類寫法在各種算術類型下都能正確處理。省略結尾單位的口語寫法（如 **「一百二」**、**「三萬四」**）會被補回下一級的單位。
+pub(crate) fn parse_integer(
+    method: ChineseCountMethod,
+    tokens: &ChineseTokens,
+) -> Result<BigUint, ChineseToNumberError> {
+    let big_10 = BigUint::from(10u8);
+
+    let mut sections: Vec<(BigUint, u32)> = Vec::new();
+    let mut section = 0u32;
+    let mut digit: Option<u8> = None;
+    let mut last_small: Option<ChineseExponent> = None;
+
+    for &(i, token) in tokens {
+        match token {
+            ChineseToken::Number(ChineseNumber::零) => {
+                if digit.is_some() {
+                    return Err(incorrect(i));
+                }
+            },
+            ChineseToken::Number(n) => {
+                if digit.is_some() {
+                    return Err(incorrect(i));
+                }
+
+                digit = Some(n.ordinal());
+            },
+            ChineseToken::Exponent(
+                e @ (ChineseExponent::十 | ChineseExponent::百 | ChineseExponent::千),
+            ) => {
+                if last_small.is_some_and(|last| last <= e) {
+                    return Err(incorrect(i));
+                }
+
+                section +=
+                    digit.take().unwrap_or(1) as u32 * 10u32.pow(e.power(method));
+                last_small = Some(e);
+            },
+            ChineseToken::Exponent(e) if e >= ChineseExponent::萬 => {
+                let power = e.power(method);
+                let pending = section + digit.take().unwrap_or(0) as u32;
+
+                let (value, magnitude) = match sections.last() {
+                    // 緊接在較小的單位之後，與它組成複合的單位，例如 **「萬萬」**、**「萬億」**
+                    Some(&(_, magnitude)) if pending == 0 && magnitude <= power => {
+                        let (value, magnitude) = sections.pop().unwrap();
+
+                        (value, magnitude + power)
+                    },
+                    _ => {
+                        let mut value = BigUint::from(pending);
+                        let mut magnitude = power;
+
+                        while sections.last().is_some_and(|(_, m)| *m < power) {
+                            let (v, m) = sections.pop().unwrap();
+
+                            value += v;
+                            magnitude = magnitude.max(m + power);
+                        }
+
+                        if value.is_zero() {
+                            // 單獨的大單位只能出現在開頭，例如 **「一億萬」** 是錯誤的
+                            if !sections.is_empty() {
+                                return Err(incorrect(i));
+                            }
+
+                            value = BigUint::one();
+                        }
+
+                        (value, magnitude)
+                    },
+                };
+
+                // 大的單位必須由大到小排列，例如 **「一萬一萬」** 是錯誤的
+                if sections.last().is_some_and(|(_, m)| *m <= magnitude) {
+                    return Err(incorrect(i));
+                }
+
+                sections.push((value * big_10.pow(power), magnitude));
+
+                section = 0;
+                last_small = None;
+            },
+            _ => return Err(incorrect(i)),
+        }
+    }
+
+    let mut value = BigUint::from(section);
+
+    if let Some(d) = digit {
+        let power = match tokens.len().checked_sub(2).map(|i| tokens[i].1) {
+            Some(ChineseToken::Exponent(e)) => e.power(method) - 1,
+            _ => 0,
+        };
+
+        value += BigUint::from(d) * big_10.pow(power);
+    }
+
+    for (v, _) in sections {
+        value += v;
+    }
+
+    Ok(value)
+}
+
+/// 解析 **「角」**、**「分」** 組成的小數部分，可省略結尾的 **「分」**。
+fn parse_currency_fraction(
+    tokens: &ChineseTokens,
+) -> Result<Vec<u8>, ChineseToNumberError> {
+    let mut fraction = [0u8; 2];
+    let mut digit: Option<u8> = None;
+    let mut last: Option<ChineseExponent> = None;
+
+    for &(i, token) in tokens {
+        match token {
+            ChineseToken::Number(ChineseNumber::零) if digit.is_none() => (),
+            ChineseToken::Number(n) if digit.is_none() => digit = Some(n.ordinal()),
+            ChineseToken::Exponent(e @ (ChineseExponent::角 | ChineseExponent::分))
+                if digit.is_some() && last.is_none_or(|last| last > e) =>
+            {
+                fraction[(ChineseExponent::角.ordinal() - e.ordinal()) as usize] =
+                    digit.take().unwrap();
+                last = Some(e);
+            },
+            _ => return Err(incorrect(i)),
+        }
+    }
+
+    if let Some(d) = digit {
+        if last == Some(ChineseExponent::角) {
+            fraction[1] = d;
+        } else {
+            return Err(incorrect(tokens[tokens.len() - 1].0));
+        }
+    }
+
+    Ok(trim_fraction(fraction.to_vec()))
+}
+
+fn parse_digits(tokens: &ChineseTokens) -> Result<Vec<u8>, ChineseToNumberError> {
+    tokens
+        .iter()
+        .map(|&(i, token)| match token {
+            ChineseToken::Number(n) => Ok(n.ordinal()),
+            _ => Err(incorrect(i)),
+        })
+        .collect()
+}
+
+#[inline]
+fn trim_fraction(mut fraction: Vec<u8>) -> Vec<u8> {
+    while fraction.last() == Some(&0) {
+        fraction.pop();
+    }
+
+    fraction
+}
+
+#[inline]
+fn digits_to_big_unsigned_integer(digits: &[u8]) -> BigUint {
+    let big_10 = BigUint::from(10u8);
+
+    digits.iter().fold(BigUint::zero(), |acc, &d| acc * &big_10 + BigUint::from(d))
+}
+
+/// 以小數點切開整數與小數部分。小數點後一律逐字讀作數字。
+fn split_point(
+    tokens: &ChineseTokens,
+) -> Result<(&ChineseTokens, Vec<u8>, usize), ChineseToNumberError> {
+    match tokens.iter().position(|(_, token)| *token == ChineseToken::Point) {
+        Some(p) => {
+            let fraction_tokens = &tokens[p + 1..];
+
+            if fraction_tokens.is_empty() {
+                return Err(incorrect(tokens[p].0));
+            }
+
+            let fraction = trim_fraction(parse_digits(fraction_tokens)?);
+
+            Ok((&tokens[..p], fraction, tokens[p].0))
+        },
+        None => Ok((tokens, Vec::new(), 0)),
+    }
+}
+
+/// 解析帶有單位的中文數字。
+pub(crate) fn parse_chinese_number(
+    method: ChineseCountMethod,
+    number_script: Option<NumberScript>,
+    chinese_number: &str,
+) -> Result<ChineseNumberParts, ChineseToNumberError> {
+    let (negative, tokens) = tokenize(chinese_number, false, number_script)?;
+
+    let currency = tokens.iter().position(|(_, token)| {
+        matches!(token, ChineseToken::Exponent(ChineseExponent::角 | ChineseExponent::分))
+    });
+
+    let (integer_tokens, fraction, fraction_index) = match currency {
+        Some(c) => {
+            // 「角」、「分」前面必須是它們的數字
+            if c == 0 {
+                return Err(incorrect(tokens[0].0));
+            }
+
+            let start = c - 1;
+
+            (&tokens[..start], parse_currency_fraction(&tokens[start..])?, tokens[start].0)
+        },
+        None => split_point(&tokens)?,
+    };
+
+    let integer = if integer_tokens.is_empty() {
+        BigUint::zero()
+    } else {
+        parse_integer(method, integer_tokens)?
+    };
+
+    Ok(ChineseNumberParts {
+        negative,
+        integer,
+        fraction,
+        fraction_index,
+    })
+}
+
+/// 解析逐字書寫、不帶單位的中文數字。
+pub(crate) fn parse_chinese_number_naive(
+    number_script: Option<NumberScript>,
+    chinese_number: &str,
+) -> Result<ChineseNumberParts, ChineseToNumberError> {
+    let (negative, tokens) = tokenize(chinese_number, true, number_script)?;
+
+    let (integer_tokens, fraction, fraction_index) = split_point(&tokens)?;
+
+    let integer = digits_to_big_unsigned_integer(&parse_digits(integer_tokens)?);
+
+    Ok(ChineseNumberParts {
+        negative,
+        integer,
+        fraction,
+        fraction_index,
+    })
+}
@@ -0,0 +1,63 @@
+#![cfg(test)]
+
+use num_bigint::BigUint;
+
+use super::*;
+use crate::ChineseCountMethod;
+
+#[test]
+fn test_parse_integer() {
+    macro_rules! test {
+        ($expect:expr, $method:expr, $value:expr) => {
+            let (_, tokens) = tokenize($value, false, None).unwrap();
+
+            assert_eq!(
+                BigUint::from($expect as u128),
+                parse_integer($method, &tokens).unwrap()
+            );
+        };
+    }
+
+    test!(0, ChineseCountMethod::TenThousand, "零");
+    test!(10, ChineseCountMethod::TenThousand, "十");
+    test!(110, ChineseCountMethod::TenThousand, "一百一十");
+    test!(120, ChineseCountMethod::TenThousand, "一百二");
+    test!(100, ChineseCountMethod::TenThousand, "百");
+    test!(1_0000, ChineseCountMethod::TenThousand, "萬");
+    test!(1_0001, ChineseCountMethod::TenThousand, "一萬零一");
+    test!(10_5000, ChineseCountMethod::TenThousand, "十萬五");
+    test!(1_0000_0000, ChineseCountMethod::TenThousand, "萬萬");
+    test!(1_0001_0000, ChineseCountMethod::TenThousand, "一億零一萬");
+    test!(1_0000_0000_0000, ChineseCountMethod::Middle, "一萬億");
+    test!(1_0001_0000_0000_0000, ChineseCountMethod::High, "一兆零一萬億");
+    test!(1_0000_0000_0000_0000_0000_0000_0000_0000, ChineseCountMethod::High, "一京");
+    test!(10_0001, ChineseCountMethod::Low, "一億零一");
+    test!(29, ChineseCountMethod::TenThousand, "이십구");
+    test!(2_0000_0000_0000_0000_0000_0000_0000_0000, ChineseCountMethod::TenThousand, "이구");
+    test!(1_0000_0000_0000_0000, ChineseCountMethod::TenThousand, "萬萬億");
+    test!(1_5000_0000_0000, ChineseCountMethod::TenThousand, "一萬二千億三千億");
+}
+
+#[test]
+fn test_parse_integer_incorrect() {
+    macro_rules! test {
+        ($index:expr, $value:expr) => {
+            let (_, tokens) = tokenize($value, false, None).unwrap();
+
+            assert_eq!(
+                Err(ChineseToNumberError::ChineseNumberIncorrect { char_index: $index }),
+                parse_integer(ChineseCountMethod::TenThousand, &tokens)
+            );
+        };
+    }
+
+    test!(1, "一二");
+    test!(2, "一十百");
+    test!(1, "三零");
+    test!(3, "一萬一萬");
+    test!(5, "一億一萬一億");
+    test!(5, "一萬億一萬億");
+    test!(2, "一億萬");
+    test!(3, "一萬萬萬");
+    test!(3, "二十億萬");
+}
@@ -0,0 +1,130 @@
+mod chinese_to_number_error;
+mod functions;
+mod traits;
+
+mod functions_test;
+
+pub use chinese_to_number_error::*;
+use functions::*;
+pub use traits::*;
+
+use crate::{is_char_of_number_script, ChineseNumber, ChinesePoint, ChineseSign, NumberScript, ScriptStyle};
+
+const SCRIPT_STYLES: [ScriptStyle; 4] = [
+    ScriptStyle::Lower {
+        circle_as_zero: false,
+    },
+    ScriptStyle::Lower {
+        circle_as_zero: true,
+    },
+    ScriptStyle::Upper,
+    ScriptStyle::HistoricUpper,
+];
+
+/// 檢查中文數字的每個字元是否都屬於指定 `NumberScript` 的語言，不區分書寫樣式，但韓文字母與韓文大寫的漢字不能混用。空白字元會被忽略。
+///
+/// ## 範例
+///
+/// ```rust
+/// use conjak_numbers::{is_number_script, NumberScript, ScriptStyle};
+///
+/// assert!(is_number_script("壹佰貳拾參", NumberScript::TraditionalChinese(ScriptStyle::Upper)));
+/// assert!(!is_number_script("壹佰貳拾參", NumberScript::Japanese(ScriptStyle::Upper)));
+/// ```
+pub fn is_number_script(chinese_number: &str, number_script: NumberScript) -> bool {
+    // 韓文的大寫使用漢字，不能和韓文字母混用
+    let style_groups: &[&[ScriptStyle]] = match number_script {
+        NumberScript::Korean(_) => &[&SCRIPT_STYLES[..2], &SCRIPT_STYLES[2..]],
+        _ => &[&SCRIPT_STYLES],
+    };
+
+    style_groups.iter().any(|styles| {
+        chinese_number.chars().filter(|c| !c.is_whitespace()).all(|c| {
+            styles.iter().any(|&style| is_char_of_number_script(number_script.with_style(style), c))
+        })
+    })
+}
+
+/// 檢查字元是否屬於指定 `NumberScript` 的語言，不區分書寫樣式。
+#[inline]
+fn is_char_of_any_style(number_script: NumberScript, character: char) -> bool {
+    SCRIPT_STYLES
+        .iter()
+        .any(|&style| is_char_of_number_script(number_script.with_style(style), character))
+}
+
+/// 根據字元推測中文數字所使用的 `NumberScript`。無法判斷語言時，依序偏好簡體中文、繁體中文、日文、韓文。
+///
+/// ## 範例
+///
+/// ```rust
+/// use conjak_numbers::{detect_number_script, NumberScript, ScriptStyle};
+///
+/// assert_eq!(
+///     Some(NumberScript::TraditionalChinese(ScriptStyle::Upper)),
+///     detect_number_script("壹佰貳拾參")
+/// );
+/// assert_eq!(
+///     Some(NumberScript::Japanese(ScriptStyle::Lower { circle_as_zero: false })),
+///     detect_number_script("三億五千万")
+/// );
+/// assert_eq!(None, detect_number_script("123"));
+/// ```
+pub fn detect_number_script(chinese_number: &str) -> Option<NumberScript> {
+    let lower = ScriptStyle::Lower {
+        circle_as_zero: false,
+    };
+
+    if chinese_number.chars().all(char::is_whitespace) {
+        return None;
+    }
+
+    let number_script = [
+        NumberScript::SimplifiedChinese(lower),
+        NumberScript::TraditionalChinese(lower),
+        NumberScript::Japanese(lower),
+        NumberScript::Korean(lower),
+    ]
+    .into_iter()
+    .find(|&number_script| is_number_script(chinese_number, number_script))?;
+
+    let is_style = |style: ScriptStyle, lower_styles: &[ScriptStyle]| {
+        chinese_number.chars().any(|c| {
+            is_char_of_number_script(number_script.with_style(style), c)
+                && !lower_styles
+                    .iter()
+                    .any(|&lower| is_char_of_number_script(number_script.with_style(lower), c))
+        })
+    };
+
+    // 只有舊式大字才有的字，例如日文的「陌」、「萬」
+    let style = if is_style(ScriptStyle::HistoricUpper, &[lower, ScriptStyle::Upper]) {
+        ScriptStyle::HistoricUpper
+    } else if is_style(ScriptStyle::Upper, &[lower]) {
+        ScriptStyle::Upper
+    } else {
+        ScriptStyle::Lower {
+            circle_as_zero: chinese_number.contains('〇'),
+        }
+    };
+
+    Some(number_script.with_style(style))
+}
+
+/// 判斷中文數字是否為逐字書寫、不帶單位的寫法，也就是只由數字、正負號與小數點組成。
+///
+/// ## 範例
+///
+/// ```rust
+/// use conjak_numbers::is_naive_chinese_number;
+///
+/// assert!(is_naive_chinese_number("二〇二五"));
+/// assert!(!is_naive_chinese_number("二千零二十五"));
+/// ```
+pub fn is_naive_chinese_number(chinese_number: &str) -> bool {
+    chinese_number.chars().filter(|c| !c.is_whitespace()).enumerate().all(|(i, c)| {
+        ChineseNumber::from_char(c).is_some()
+            || ChinesePoint::from_char(c).is_some()
+            || (i == 0 && ChineseSign::from_char(c).is_some())
+    })
+}
@@ -0,0 +1,82 @@
+use super::*;
+use crate::{ChineseCountMethod, NumberScript};
+
+/// 讓字串擁有轉成 Rust 程式語言的基本數值型別的能力。
+pub trait ChineseToNumber<T> {
+    /// 將帶有單位的中文數字轉成數值。可以接受所有 `NumberScript` 的寫法，以及省略結尾單位的口語寫法。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use conjak_numbers::{ChineseCountMethod, ChineseToNumber};
+    ///
+    /// assert_eq!(123i8, "一百二十三".to_number(ChineseCountMethod::TenThousand).unwrap());
+    /// assert_eq!(-30303i16, "負三萬零三百零三".to_number(ChineseCountMethod::TenThousand).unwrap());
+    /// assert_eq!(2300u64, "兩千三".to_number(ChineseCountMethod::TenThousand).unwrap());
+    /// assert_eq!(1000000u64, "一兆".to_number(ChineseCountMethod::Low).unwrap());
+    /// assert_eq!(30000u32, "삼만".to_number(ChineseCountMethod::TenThousand).unwrap());
+    /// ```
+    fn to_number(&self, method: ChineseCountMethod) -> Result<T, ChineseToNumberError>;
+
+    /// 將逐字書寫、不帶單位的中文數字轉成數值。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use conjak_numbers::ChineseToNumber;
+    ///
+    /// assert_eq!(123i8, "一二三".to_number_naive().unwrap());
+    /// assert_eq!(2025u16, "二〇二五".to_number_naive().unwrap());
+    /// ```
+    fn to_number_naive(&self) -> Result<T, ChineseToNumberError>;
+
+    /// 以指定的 `NumberScript` 將帶有單位的中文數字轉成數值。不屬於該語言的字元會被視為錯誤，而韓文的 `구` 只有在韓文中才會被讀作單位 `溝`。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use conjak_numbers::{ChineseCountMethod, ChineseToNumber, ChineseToNumberError, NumberScript, ScriptStyle};
+    ///
+    /// let korean = NumberScript::Korean(ScriptStyle::Lower { circle_as_zero: false });
+    ///
+    /// assert_eq!(30000u32, "삼만".to_number_with_script(korean, ChineseCountMethod::TenThousand).unwrap());
+    /// assert_eq!(
+    ///     Err(ChineseToNumberError::ChineseNumberIncorrect { char_index: 0 }),
+    ///     ChineseToNumber::<u32>::to_number_with_script("三萬", korean, ChineseCountMethod::TenThousand)
+    /// );
+    /// ```
+    fn to_number_with_script(
+        &self,
+        number_script: NumberScript,
+        method: ChineseCountMethod,
+    ) -> Result<T, ChineseToNumberError>;
+
+    /// 以指定的 `NumberScript` 將逐字書寫、不帶單位的中文數字轉成數值。
+    fn to_number_naive_with_script(&self, number_script: NumberScript) -> Result<T, ChineseToNumberError>;
+}
+
+impl<S: AsRef<str> + ?Sized, T: FromChineseNumberParts> ChineseToNumber<T> for S {
+    #[inline]
+    fn to_number(&self, method: ChineseCountMethod) -> Result<T, ChineseToNumberError> {
+        T::from_chinese_number_parts(parse_chinese_number(method, None, self.as_ref())?)
+    }
+
+    #[inline]
+    fn to_number_naive(&self) -> Result<T, ChineseToNumberError> {
+        T::from_chinese_number_parts(parse_chinese_number_naive(None, self.as_ref())?)
+    }
+
+    #[inline]
+    fn to_number_with_script(
+        &self,
+        number_script: NumberScript,
+        method: ChineseCountMethod,
+    ) -> Result<T, ChineseToNumberError> {
+        T::from_chinese_number_parts(parse_chinese_number(method, Some(number_script), self.as_ref())?)
+    }
+
+    #[inline]
+    fn to_number_naive_with_script(&self, number_script: NumberScript) -> Result<T, ChineseToNumberError> {
+        T::from_chinese_number_parts(parse_chinese_number_naive(Some(number_script), self.as_ref())?)
+    }
+}
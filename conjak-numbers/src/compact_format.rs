@@ -0,0 +1,48 @@
+use serde::Deserialize;
+
+use crate::RoundingMode;
+
+/// 縮寫寫法中，單位前面的數字的寫法。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompactDigits {
+    /// 阿拉伯數字，例如 **「12.3万」**。
+    #[default]
+    Arabic,
+    /// 中文數字，例如 **「十二点三万」**。
+    Chinese,
+}
+
+/// 縮寫寫法的格式，包含數字的寫法、有效位數與捨入方式。
+///
+/// 預設值為阿拉伯數字、三位有效數字、四捨五入。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[serde(default)]
+pub struct CompactFormat {
+    pub digits: CompactDigits,
+    /// 有效位數，至少為一位。整數部分的位數超過有效位數時不會被捨去，例如 **「1234万」**。
+    pub significant_figures: usize,
+    pub rounding: RoundingMode,
+}
+
+impl CompactFormat {
+    #[inline]
+    pub const fn new(
+        digits: CompactDigits,
+        significant_figures: usize,
+        rounding: RoundingMode,
+    ) -> Self {
+        CompactFormat {
+            digits,
+            significant_figures,
+            rounding,
+        }
+    }
+}
+
+impl Default for CompactFormat {
+    #[inline]
+    fn default() -> Self {
+        CompactFormat::new(CompactDigits::Arabic, 3, RoundingMode::HalfUp)
+    }
+}
@@ -0,0 +1,340 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+use std::error::Error;
+
+use num_bigint::BigUint;
+#[allow(unused_imports)]
+use num_traits::float::FloatCore;
+use num_traits::{FromPrimitive, One, Zero};
+
+use crate::RoundingMode;
+
+/// 任意精度的十進位數值。整數部分以 `BigUint` 保存，小數部分逐位保存，不會有浮點數的捨入誤差。
+///
+/// ## 範例
+///
+/// ```rust
+/// use conjak_numbers::Decimal;
+///
+/// let value: Decimal = "-123456789012345678901234567890.125".parse().unwrap();
+///
+/// assert!(value.is_negative());
+/// assert_eq!(&[1, 2, 5], value.fraction());
+/// assert_eq!("-123456789012345678901234567890.125", value.to_string());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+pub struct Decimal {
+    negative: bool,
+    integer: BigUint,
+    fraction: Vec<u8>,
+}
+
+impl Decimal {
+    /// 建立十進位數值。小數部分的每個元素都必須是 `0` 到 `9`，結尾的零會被去掉。
+    ///
+    /// ## Panics
+    ///
+    /// 小數部分有大於 `9` 的元素時會 panic。
+    #[inline]
+    pub fn new(negative: bool, integer: BigUint, mut fraction: Vec<u8>) -> Self {
+        assert!(
+            fraction.iter().all(|&d| d < 10),
+            "every fraction digit of a decimal must be between 0 and 9"
+        );
+
+        while fraction.last() == Some(&0) {
+            fraction.pop();
+        }
+
+        // 零沒有正負之分
+        let negative = negative && !(integer.is_zero() && fraction.is_empty());
+
+        Decimal {
+            negative,
+            integer,
+            fraction,
+        }
+    }
+
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// 整數部分的絕對值。
+    #[inline]
+    pub fn integer(&self) -> &BigUint {
+        &self.integer
+    }
+
+    /// 小數點後的每一位數字，不含結尾的零。
+    #[inline]
+    pub fn fraction(&self) -> &[u8] {
+        &self.fraction
+    }
+
+    /// 將 `f64` 浮點數轉成十進位數值。整數部分使用浮點數的精確值，小數部分使用能還原出同一個浮點數的最短寫法。`NaN` 與無限大會回傳 `None`。
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+
+        let integer = BigUint::from_f64(value.abs().trunc())?;
+
+        Some(Decimal::new(value < 0.0, integer, shortest_fraction_digits(value.abs().to_string())))
+    }
+
+    /// 將 `f32` 浮點數轉成十進位數值，規則與 `from_f64` 相同。
+    pub fn from_f32(value: f32) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+
+        let integer = BigUint::from_f32(value.abs().trunc())?;
+
+        Some(Decimal::new(value < 0.0, integer, shortest_fraction_digits(value.abs().to_string())))
+    }
+
+    /// 乘上 `10^n`，也就是把小數點向右移動 `n` 位；`n` 為負數時向左移動。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use conjak_numbers::Decimal;
+    ///
+    /// let value: Decimal = "0.255".parse().unwrap();
+    ///
+    /// assert_eq!("25.5", value.shift_point(2).to_string());
+    /// assert_eq!("2550", value.shift_point(4).to_string());
+    /// assert_eq!("0.00255", value.shift_point(-2).to_string());
+    /// ```
+    pub fn shift_point(&self, n: isize) -> Self {
+        let mut digits: Vec<u8> = self.integer.to_string().bytes().map(|b| b - b'0').collect();
+        let mut point = digits.len() as isize + n;
+
+        digits.extend_from_slice(&self.fraction);
+
+        if point < 0 {
+            digits.splice(0..0, core::iter::repeat_n(0, point.unsigned_abs()));
+            point = 0;
+        }
+
+        let point = point as usize;
+
+        if digits.len() < point {
+            digits.resize(point, 0);
+        }
+
+        let big_10 = BigUint::from(10u8);
+
+        let integer = digits[..point]
+            .iter()
+            .fold(BigUint::zero(), |acc, &d| acc * &big_10 + BigUint::from(d));
+
+        Decimal::new(self.negative, integer, digits[point..].to_vec())
+    }
+
+    /// 只保留 `precision` 位小數，多餘的位數依 `rounding` 捨入。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use conjak_numbers::{Decimal, RoundingMode};
+    ///
+    /// let value: Decimal = "-2.345".parse().unwrap();
+    ///
+    /// assert_eq!("-2.34", value.round(2, RoundingMode::Truncate).to_string());
+    /// assert_eq!("-2.35", value.round(2, RoundingMode::HalfUp).to_string());
+    /// assert_eq!("-2.34", value.round(2, RoundingMode::HalfEven).to_string());
+    /// assert_eq!("-3", value.round(0, RoundingMode::Up).to_string());
+    /// ```
+    pub fn round(&self, precision: usize, rounding: RoundingMode) -> Self {
+        if self.fraction.len() <= precision {
+            return self.clone();
+        }
+
+        let (kept, rest) = self.fraction.split_at(precision);
+
+        // 結尾的零已被去掉，因此 `rest` 一定不是零
+        let carry = match rounding {
+            RoundingMode::Truncate => false,
+            RoundingMode::Up => true,
+            RoundingMode::HalfUp => rest[0] >= 5,
+            RoundingMode::HalfEven => {
+                let odd = match kept.last() {
+                    Some(d) => d % 2 == 1,
+                    None => self.integer.bit(0),
+                };
+
+                rest[0] > 5 || (rest[0] == 5 && (rest.len() > 1 || odd))
+            },
+        };
+
+        let mut integer = self.integer.clone();
+        let mut fraction = kept.to_vec();
+
+        if carry {
+            let mut overflow = true;
+
+            for d in fraction.iter_mut().rev() {
+                if *d == 9 {
+                    *d = 0;
+                } else {
+                    *d += 1;
+                    overflow = false;
+
+                    break;
+                }
+            }
+
+            if overflow {
+                integer += BigUint::one();
+            }
+        }
+
+        Decimal::new(self.negative, integer, fraction)
+    }
+}
+
+#[inline]
+fn shortest_fraction_digits(s: String) -> Vec<u8> {
+    match s.split_once('.') {
+        Some((_, digits)) => digits.bytes().map(|b| b - b'0').collect(),
+        None => Vec::new(),
+    }
+}
+
+macro_rules! impl_from_unsigned_integer {
+    ($($t:ty),* $(,)*) => {
+        $(
+            impl From<$t> for Decimal {
+                #[inline]
+                fn from(value: $t) -> Self {
+                    Decimal::new(false, BigUint::from(value), Vec::new())
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_from_signed_integer {
+    ($($t:ty),* $(,)*) => {
+        $(
+            impl From<$t> for Decimal {
+                #[inline]
+                fn from(value: $t) -> Self {
+                    Decimal::new(value < 0, BigUint::from(value.unsigned_abs()), Vec::new())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned_integer!(u8, u16, u32, u64, u128, usize);
+impl_from_signed_integer!(i8, i16, i32, i64, i128, isize);
+
+impl Display for Decimal {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        if self.negative {
+            f.write_str("-")?;
+        }
+
+        Display::fmt(&self.integer, f)?;
+
+        if !self.fraction.is_empty() {
+            f.write_str(".")?;
+
+            for d in self.fraction.iter() {
+                Display::fmt(d, f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// 將字串轉成 `Decimal` 時發生的錯誤。
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ParseDecimalError {
+    Empty,
+    InvalidDigit { char_index: usize },
+}
+
+impl Display for ParseDecimalError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            ParseDecimalError::Empty => f.write_str("decimal is empty"),
+            ParseDecimalError::InvalidDigit {
+                char_index,
+            } => write!(f, "invalid digit found in decimal (position: {char_index})"),
+        }
+    }
+}
+
+impl Error for ParseDecimalError {}
+
+impl FromStr for Decimal {
+    type Err = ParseDecimalError;
+
+    /// 接受 `[+-]整數[.小數]` 的寫法，整數或小數其中一邊可以省略，但不能兩邊都省略。
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let big_10 = BigUint::from(10u8);
+
+        let mut chars = s.chars().enumerate().peekable();
+
+        let negative = match chars.peek() {
+            Some((_, '-')) => {
+                chars.next();
+
+                true
+            },
+            Some((_, '+')) => {
+                chars.next();
+
+                false
+            },
+            _ => false,
+        };
+
+        let mut integer = BigUint::zero();
+        let mut fraction = Vec::new();
+        let mut has_digit = false;
+        let mut after_point = false;
+
+        for (i, c) in chars {
+            match c {
+                '0'..='9' => {
+                    let d = c as u8 - b'0';
+
+                    if after_point {
+                        fraction.push(d);
+                    } else {
+                        integer = integer * &big_10 + BigUint::from(d);
+                    }
+
+                    has_digit = true;
+                },
+                '.' if !after_point => after_point = true,
+                _ => {
+                    return Err(ParseDecimalError::InvalidDigit {
+                        char_index: i,
+                    })
+                },
+            }
+        }
+
+        if !has_digit {
+            return Err(ParseDecimalError::Empty);
+        }
+
+        Ok(Decimal::new(negative, integer, fraction))
+    }
+}
@@ -0,0 +1,92 @@
+use serde::Deserialize;
+
+use crate::{DigitWidth, NumberScript};
+
+/// 阿拉伯數字的分組方式。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DigitGrouping {
+    /// 每三位一組，例如 **「123,456,789」**。
+    #[default]
+    Thousand,
+    /// 每四位一組，與萬進的單位對齊，例如 **「1,2345,6789」**。
+    TenThousand,
+    /// 不分組，例如 **「123456789」**。
+    None,
+}
+
+/// 阿拉伯數字的寫法，包含數字寬度、分組方式、分隔符號與小數點。
+///
+/// 預設值為半形數字、每三位一組。分隔符號與小數點沒有指定時，依照書寫方式與數字寬度決定，請見 `DigitFormat::separator_for`、`DigitFormat::decimal_mark_for`。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+#[serde(default)]
+pub struct DigitFormat {
+    pub width: DigitWidth,
+    pub grouping: DigitGrouping,
+    /// 分組之間的符號。
+    pub separator: Option<char>,
+    /// 整數與小數之間的符號。
+    pub decimal_mark: Option<char>,
+}
+
+impl DigitFormat {
+    #[inline]
+    pub const fn new(
+        width: DigitWidth,
+        grouping: DigitGrouping,
+        separator: Option<char>,
+        decimal_mark: Option<char>,
+    ) -> Self {
+        DigitFormat {
+            width,
+            grouping,
+            separator,
+            decimal_mark,
+        }
+    }
+
+    /// 取得分組之間的符號。沒有指定時，越南文使用 **「.」**，中文與日文的全形數字使用 **「，」**，其他情況使用 **「,」**。
+    #[inline]
+    pub const fn separator_for(self, number_script: NumberScript) -> char {
+        match self.separator {
+            Some(c) => c,
+            None => match punctuation(self.width, number_script) {
+                Punctuation::Vietnamese => '.',
+                Punctuation::FullWidth => '，',
+                Punctuation::HalfWidth => ',',
+            },
+        }
+    }
+
+    /// 取得整數與小數之間的符號。沒有指定時，越南文使用 **「,」**，中文與日文的全形數字使用 **「．」**，其他情況使用 **「.」**。
+    #[inline]
+    pub const fn decimal_mark_for(self, number_script: NumberScript) -> char {
+        match self.decimal_mark {
+            Some(c) => c,
+            None => match punctuation(self.width, number_script) {
+                Punctuation::Vietnamese => ',',
+                Punctuation::FullWidth => '．',
+                Punctuation::HalfWidth => '.',
+            },
+        }
+    }
+}
+
+enum Punctuation {
+    HalfWidth,
+    FullWidth,
+    Vietnamese,
+}
+
+/// 越南文以 **「.」** 分組、以 **「,」** 作為小數點。韓文與越南文不使用全形的標點符號，即使數字是全形的。中文與日文只依數字寬度決定。
+#[inline]
+const fn punctuation(width: DigitWidth, number_script: NumberScript) -> Punctuation {
+    match number_script {
+        NumberScript::Vietnamese(_) => Punctuation::Vietnamese,
+        NumberScript::Korean(_) => Punctuation::HalfWidth,
+        _ => match width {
+            DigitWidth::Full => Punctuation::FullWidth,
+            DigitWidth::Half => Punctuation::HalfWidth,
+        },
+    }
+}
@@ -0,0 +1,79 @@
+use serde::Deserialize;
+
+/// 小數部分的讀法。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FractionMode {
+    /// 貨幣單位，例如 **「三角四分五釐」**，最多三位。
+    #[default]
+    Currency,
+    /// 以小數點逐位讀出，例如 **「三點一四一五九」**，位數不受限制。
+    DecimalPoint,
+    /// 傳統的小數單位 **分、釐、毫、絲、忽、微、纖、沙、塵、埃、渺、漠**，最多十二位。
+    SmallUnit,
+}
+
+impl FractionMode {
+    /// 這種讀法最多能表示的小數位數。
+    #[inline]
+    pub const fn max_precision(self) -> usize {
+        match self {
+            Self::Currency => 3,
+            Self::DecimalPoint => usize::MAX,
+            Self::SmallUnit => 12,
+        }
+    }
+}
+
+/// 捨去多餘小數位數的方式。一律以絕對值進行捨入，因此負數與正數對稱。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundingMode {
+    /// 無條件捨去。
+    Truncate,
+    /// 無條件進位。
+    Up,
+    /// 四捨五入。
+    #[default]
+    HalfUp,
+    /// 四捨六入五成雙。
+    HalfEven,
+}
+
+/// 小數部分的格式，包含讀法、保留的位數與捨入方式。
+///
+/// 預設值為貨幣單位、保留兩位、四捨五入，也就是 **「角」**、**「分」** 的傳統寫法。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[serde(default)]
+pub struct FractionFormat {
+    pub mode: FractionMode,
+    /// 保留的小數位數，超過 `mode` 所能表示的位數時以 `mode` 為準。
+    pub precision: usize,
+    pub rounding: RoundingMode,
+}
+
+impl FractionFormat {
+    #[inline]
+    pub const fn new(mode: FractionMode, precision: usize, rounding: RoundingMode) -> Self {
+        FractionFormat {
+            mode,
+            precision,
+            rounding,
+        }
+    }
+
+    /// 實際保留的小數位數。
+    #[inline]
+    pub const fn effective_precision(&self) -> usize {
+        let max = self.mode.max_precision();
+
+        if self.precision < max { self.precision } else { max }
+    }
+}
+
+impl Default for FractionFormat {
+    #[inline]
+    fn default() -> Self {
+        FractionFormat::new(FractionMode::Currency, 2, RoundingMode::HalfUp)
+    }
+}
@@ -0,0 +1,41 @@
+use serde::Deserialize;
+
+/// 假名的種類。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KanaScript {
+    /// 平假名，例如 **「さんびゃく」**。
+    #[default]
+    Hiragana,
+    /// 片假名，例如 **「サンビャク」**。
+    Katakana,
+}
+
+/// **「十」** 接在 **「兆」**、**「京」** 這類單位前面時的促音寫法。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TenSokuon {
+    /// **「じゅっ」**，例如 **「じゅっちょう」**。
+    #[default]
+    Ju,
+    /// 傳統的 **「じっ」**，例如 **「じっちょう」**。
+    Ji,
+}
+
+/// 日文假名讀音的格式。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+#[serde(default)]
+pub struct KanaFormat {
+    pub script: KanaScript,
+    pub ten_sokuon: TenSokuon,
+}
+
+impl KanaFormat {
+    #[inline]
+    pub const fn new(script: KanaScript, ten_sokuon: TenSokuon) -> Self {
+        KanaFormat {
+            script,
+            ten_sokuon,
+        }
+    }
+}
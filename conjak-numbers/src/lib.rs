@@ -3,32 +3,36 @@
 
 Convert primitive numbers to Chinese numbers, or parse Chinese numbers to primitive numbers.
 
-This crate can convert Rust's primitive number data types to Chinese numbers as strings. For example, **123** can be converted into **一二三**, **一百二十三** or **壹佰貳拾參**. It supports both Traditional Chinese and Simple Chinese, and it supports different methods to count the scale as well. Also, Chinese numbers in strings can be parsed to primitive number data types.
+This crate can convert Rust's primitive number data types to Chinese numbers as strings. For example, **123** can be converted into **一二三**, **一百二十三** or **壹佰貳拾參**. It supports Simplified Chinese, Traditional Chinese, Japanese, Korean and Vietnamese through `NumberScript`, and it supports different methods to count the scale as well. Also, numbers written in any of these scripts can be parsed to primitive number data types or `Decimal`, including colloquial elisions such as **一百二** and **三萬四**.
 
 ## Example
 
 ```rust
-# #[cfg(all(feature = "number-to-chinese", feature = "chinese-to-number"))]
-# {
-use conjak_numbers::{ChineseCase, ChineseCountMethod, ChineseVariant, NumberToChinese, ChineseToNumber};
+use conjak_numbers::{ChineseCountMethod, ChineseToNumber, NumberScript, NumberToChinese, ScriptStyle};
 
-assert_eq!("一二三", 123i8.to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower));
+let traditional = NumberScript::TraditionalChinese(ScriptStyle::Lower { circle_as_zero: false });
+let traditional_upper = NumberScript::TraditionalChinese(ScriptStyle::Upper);
+let simplified_upper = NumberScript::SimplifiedChinese(ScriptStyle::Upper);
 
-assert_eq!("壹佰貳拾參", 123i8.to_chinese(ChineseVariant::Traditional, ChineseCase::Upper, ChineseCountMethod::TenThousand).unwrap());
-assert_eq!("壹佰贰拾叁", 123i8.to_chinese(ChineseVariant::Simple, ChineseCase::Upper, ChineseCountMethod::TenThousand).unwrap());
+assert_eq!("一二三", 123i8.to_chinese_naive(traditional));
 
-assert_eq!("一百二十三", 123i8.to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, ChineseCountMethod::TenThousand).unwrap());
+assert_eq!("壹佰貳拾參", 123i8.to_chinese(traditional_upper, ChineseCountMethod::TenThousand).unwrap());
+assert_eq!("壹佰贰拾叁", 123i8.to_chinese(simplified_upper, ChineseCountMethod::TenThousand).unwrap());
 
-assert_eq!("一極二載三正四澗五溝六穰七秭八垓九京零一億二萬三千四百五十六", 1234567890123456i64.to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, ChineseCountMethod::Low).unwrap());
-assert_eq!("十二穰三千四百五十六秭七千八百九十垓一千二百三十四京五千六百七十八兆九千零一十二億三千四百五十六萬七千八百九十", 123456789012345678901234567890i128.to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, ChineseCountMethod::TenThousand).unwrap());
-assert_eq!("十二萬三千四百五十六京七千八百九十萬一千二百三十四兆五千六百七十八萬九千零一十二億三千四百五十六萬七千八百九十", 123456789012345678901234567890i128.to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, ChineseCountMethod::Middle).unwrap());
-assert_eq!("十二萬三千四百五十六億七千八百九十萬一千二百三十四兆五千六百七十八萬九千零一十二億三千四百五十六萬七千八百九十", 123456789012345678901234567890i128.to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, ChineseCountMethod::High).unwrap());
+assert_eq!("一百二十三", 123i8.to_chinese(traditional, ChineseCountMethod::TenThousand).unwrap());
 
-assert_eq!("一角二分", 0.12f64.to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, ChineseCountMethod::TenThousand).unwrap());
+assert_eq!("一極二載三正四澗五溝六穰七秭八垓九京零一億二萬三千四百五十六", 1234567890123456i64.to_chinese(traditional, ChineseCountMethod::Low).unwrap());
+assert_eq!("十二穰三千四百五十六秭七千八百九十垓一千二百三十四京五千六百七十八兆九千零一十二億三千四百五十六萬七千八百九十", 123456789012345678901234567890i128.to_chinese(traditional, ChineseCountMethod::TenThousand).unwrap());
+assert_eq!("十二萬三千四百五十六京七千八百九十萬一千二百三十四兆五千六百七十八萬九千零一十二億三千四百五十六萬七千八百九十", 123456789012345678901234567890i128.to_chinese(traditional, ChineseCountMethod::Middle).unwrap());
+assert_eq!("十二萬三千四百五十六億七千八百九十萬一千二百三十四兆五千六百七十八萬九千零一十二億三千四百五十六萬七千八百九十", 123456789012345678901234567890i128.to_chinese(traditional, ChineseCountMethod::High).unwrap());
+
+assert_eq!("一角二分", 0.12f64.to_chinese(traditional, ChineseCountMethod::TenThousand).unwrap());
 
 assert_eq!(123i8, "一二三".to_number_naive().unwrap());
 
 assert_eq!(123i8, "一百二十三".to_number(ChineseCountMethod::TenThousand).unwrap());
+assert_eq!(123i8, "壹佰貳拾參".to_number(ChineseCountMethod::TenThousand).unwrap());
+assert_eq!(30000u32, "삼만".to_number(ChineseCountMethod::TenThousand).unwrap());
 assert_eq!(-30303i16, "負三萬零三百零三".to_number(ChineseCountMethod::TenThousand).unwrap());
 assert_eq!(3212345678u32, "三十二億一千二百三十四萬五千六百七十八".to_number(ChineseCountMethod::TenThousand).unwrap());
 assert_eq!(10010001001001001000u64, "一千零一京零一兆零一十億零一百萬一千".to_number(ChineseCountMethod::TenThousand).unwrap());
@@ -43,18 +47,9 @@ assert_eq!(2300u64, "兩千三".to_number(ChineseCountMethod::TenThousand).unwra
 assert_eq!(34000u64, "三萬四".to_number(ChineseCountMethod::TenThousand).unwrap());
 assert_eq!(105000u64, "十萬五".to_number(ChineseCountMethod::TenThousand).unwrap());
 assert_eq!(150000000u64, "一億五".to_number(ChineseCountMethod::TenThousand).unwrap());
-# }
-```
-
-## No Std
-
-Disable the default features to compile this crate without std.
 
-```toml
-[dependencies.chinese-number]
-version = "*"
-default-features = false
-features = ["number-to-chinese", "chinese-to-number"]
+// Big units must be written in decreasing order
+assert!(ChineseToNumber::<u64>::to_number("一萬一萬", ChineseCountMethod::TenThousand).is_err());
 ```
  */
 
@@ -62,13 +57,31 @@ features = ["number-to-chinese", "chinese-to-number"]
 
 extern crate alloc;
 
+mod chinese_to_number;
 mod number_to_chinese;
 
 mod chinese_case;
 mod chinese_characters;
 mod chinese_count_method;
+mod compact_format;
+mod decimal;
+mod digit_format;
+mod fraction_format;
+mod kana_format;
+mod locale_rules;
+mod mixed_format;
+mod spacing_format;
 
 pub use chinese_case::*;
 pub(crate) use chinese_characters::*;
 pub use chinese_count_method::*;
+pub use chinese_to_number::*;
+pub use compact_format::*;
+pub use decimal::*;
+pub use digit_format::*;
+pub use fraction_format::*;
+pub use kana_format::*;
+pub use locale_rules::*;
+pub use mixed_format::*;
 pub use number_to_chinese::*;
+pub use spacing_format::*;
@@ -0,0 +1,97 @@
+use serde::Deserialize;
+
+/// 數字開頭的 **「2」** 寫成 **「兩」**（**「两」**）的時機。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TwoForm {
+    /// 一律寫 **「二」**，例如 **「二千」**、**「二萬」**。
+    #[default]
+    Er,
+    /// 在 **「千」**、**「萬」**、**「億」**……前面寫 **「兩」**，例如 **「两千」**、**「两万」**，但仍寫 **「二百」**。
+    BeforeThousand,
+    /// 在 **「百」** 以上的單位前面寫 **「兩」**，例如 **「兩百」**。
+    BeforeHundred,
+}
+
+/// 各地區在 **「一」** 的省略與 **「兩」** 的使用上的寫法。
+///
+/// 只有 **「兩」** 與數字開頭的 **「一」** 會受影響，**「零」** 的寫法不變。**「兩」** 只用於小寫的中文，其他書寫方式仍寫 **「二」**。
+///
+/// 預設值與 `to_chinese` 相同：**「十二」**、**「一百一十」**、**「一千」**、**「一萬」**、**「二千」**。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[serde(default)]
+pub struct LocaleRules {
+    /// 數字開頭的 **「十」** 前面寫 **「一」**，例如 **「一十二」**。
+    pub one_before_leading_ten: bool,
+    /// 數字中間的 **「十」** 前面寫 **「一」**，例如 **「一百一十」**，否則為 **「百十」**。
+    pub one_before_inner_ten: bool,
+    /// **「百」**、**「千」** 前面寫 **「一」**，例如 **「一千一百」**，否則為 **「千百」**。
+    pub one_before_hundred_and_thousand: bool,
+    /// 數字開頭的 **「萬」**、**「億」**……前面寫 **「一」**，例如 **「一萬」**，否則為 **「萬」**。
+    pub one_before_myriad: bool,
+    pub two: TwoForm,
+}
+
+impl LocaleRules {
+    #[inline]
+    pub const fn new(
+        one_before_leading_ten: bool,
+        one_before_inner_ten: bool,
+        one_before_hundred_and_thousand: bool,
+        one_before_myriad: bool,
+        two: TwoForm,
+    ) -> Self {
+        LocaleRules {
+            one_before_leading_ten,
+            one_before_inner_ten,
+            one_before_hundred_and_thousand,
+            one_before_myriad,
+            two,
+        }
+    }
+}
+
+impl Default for LocaleRules {
+    #[inline]
+    fn default() -> Self {
+        LocaleRules::new(false, true, true, true, TwoForm::Er)
+    }
+}
+
+/// 內建的地區寫法。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LocalePreset {
+    /// 中國大陸，例如 **「两千」**、**「两万」**、**「一百一十」**、**「二百」**。
+    Cn,
+    /// 新加坡，與中國大陸相同。
+    Sg,
+    /// 臺灣的書面寫法，例如 **「二千」**、**「一百一十」**。
+    Tw,
+    /// 香港，例如 **「兩千」**、**「兩百」**、**「一百一十」**。
+    Hk,
+    /// 澳門，與香港相同。
+    Mo,
+    /// 日本，例如 **「千」**、**「百十」**、**「一万」**、**「二千」**。
+    Jp,
+}
+
+impl LocalePreset {
+    /// 取得這個地區的寫法。
+    #[inline]
+    pub const fn rules(self) -> LocaleRules {
+        match self {
+            Self::Cn | Self::Sg => LocaleRules::new(false, true, true, true, TwoForm::BeforeThousand),
+            Self::Tw => LocaleRules::new(false, true, true, true, TwoForm::Er),
+            Self::Hk | Self::Mo => LocaleRules::new(false, true, true, true, TwoForm::BeforeHundred),
+            Self::Jp => LocaleRules::new(false, false, false, true, TwoForm::Er),
+        }
+    }
+}
+
+impl From<LocalePreset> for LocaleRules {
+    #[inline]
+    fn from(preset: LocalePreset) -> Self {
+        preset.rules()
+    }
+}
@@ -0,0 +1,56 @@
+use serde::Deserialize;
+
+/// 阿拉伯數字的寬度。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DigitWidth {
+    /// 半形數字，例如 **「123」**。
+    #[default]
+    Half,
+    /// 全形數字，例如 **「１２３」**。
+    Full,
+}
+
+impl DigitWidth {
+    /// 將 `0` 到 `9` 轉成這種寬度的數字字元。呼叫者須保證 `d` 小於 `10`。
+    #[inline]
+    pub(crate) const fn digit(self, d: u8) -> char {
+        debug_assert!(d < 10);
+
+        match self {
+            Self::Half => (b'0' + d) as char,
+            Self::Full => match char::from_u32('０' as u32 + d as u32) {
+                Some(c) => c,
+                None => unreachable!(),
+            },
+        }
+    }
+}
+
+/// 阿拉伯數字搭配大單位的寫法，例如 **「1億2345万6789」**。
+///
+/// 預設值為半形數字，並略過全為零的四位數組。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[serde(default)]
+pub struct MixedFormat {
+    pub width: DigitWidth,
+    /// 略過全為零的四位數組，例如 **「1億5」**；不略過時寫成 **「1億0万5」**。
+    pub omit_empty_groups: bool,
+}
+
+impl MixedFormat {
+    #[inline]
+    pub const fn new(width: DigitWidth, omit_empty_groups: bool) -> Self {
+        MixedFormat {
+            width,
+            omit_empty_groups,
+        }
+    }
+}
+
+impl Default for MixedFormat {
+    #[inline]
+    fn default() -> Self {
+        MixedFormat::new(DigitWidth::Half, true)
+    }
+}
@@ -0,0 +1,136 @@
+use alloc::string::{String, ToString};
+
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use super::{
+    from_decimal_to_chinese,
+    functions::{big_unsigned_integer_to_numerals_by_method, numerals_to_chinese, Numeral},
+    IntoDecimal,
+};
+use crate::{
+    chinese_characters::ChineseNumber, ChineseCountMethod, CompactDigits, CompactFormat, Decimal,
+    LocaleRules, NumberScript, NumberToChineseError,
+};
+
+/// 依有效位數捨入以 `10^power` 為單位的數值，回傳單位前面的數字。
+#[inline]
+fn mantissa(value: &Decimal, power: u32, format: CompactFormat) -> Decimal {
+    let mantissa = value.shift_point(-(power as isize));
+
+    let integer_digits =
+        if mantissa.integer().is_zero() { 0 } else { mantissa.integer().to_string().len() };
+
+    let significant_figures = format.significant_figures.max(1);
+
+    mantissa.round(significant_figures.saturating_sub(integer_digits), format.rounding)
+}
+
+/// 相鄰兩個縮寫單位之間的次方差，以及最大的單位的次方。**「下數」** 每一位都有單位，其他用法每四位一個單位，**「中數」**、**「上數」** 也會用到 **「萬億」**、**「億兆」** 這類組合的單位。
+#[inline]
+const fn unit_powers(method: ChineseCountMethod) -> (u32, u32) {
+    match method {
+        ChineseCountMethod::Low => (1, 15),
+        ChineseCountMethod::TenThousand => (4, 48),
+        ChineseCountMethod::Middle => (4, 92),
+        ChineseCountMethod::High => (4, u32::MAX),
+    }
+}
+
+/// `10^power` 的單位，也就是把 **「一萬億」** 這類寫法前面的 **「一」** 去掉。
+fn unit_to_chinese(number_script: NumberScript, method: ChineseCountMethod, power: u32) -> String {
+    let numerals = big_unsigned_integer_to_numerals_by_method(
+        LocaleRules::default(),
+        method,
+        BigUint::from(10u8).pow(power),
+    );
+
+    debug_assert_eq!(Some(&Numeral::Number(ChineseNumber::一)), numerals.first());
+
+    numerals_to_chinese(number_script, &numerals[1..])
+}
+
+/// 將十進位數值轉成縮寫寫法，例如 **「12.3万」**、**「3.4억」**。
+///
+/// 會選出不大於數值的最大單位（**「萬」** 以上），單位前面的數字依 `format` 的有效位數捨入，結尾的零會被去掉。**「中數」** 與 **「上數」** 會用 **「萬億」** 這類組合的單位，讓單位前面的數字小於一萬。數值小於一萬時不加單位，只依有效位數捨入小數部分。捨入後達到下一個單位時會改用下一個單位，例如 **「9999.9万」** 在三位有效數字下寫成 **「1亿」**。
+pub fn from_decimal_to_compact(
+    number_script: NumberScript,
+    method: ChineseCountMethod,
+    format: CompactFormat,
+    value: &Decimal,
+) -> Result<String, NumberToChineseError> {
+    let big_10 = BigUint::from(10u8);
+
+    let (step, max_power) = unit_powers(method);
+
+    let digits = value.integer().to_string().len() as u32;
+
+    let mut power = if digits > 4 { (digits - 1) / step * step } else { 0 };
+
+    power = power.min(max_power);
+
+    let mut m = mantissa(value, power, format);
+
+    // 捨入後可能進位到下一個單位
+    loop {
+        let next = if power == 0 { 4 } else { power + step };
+
+        if next > max_power || *m.integer() < big_10.pow(next - power) {
+            break;
+        }
+
+        power = next;
+        m = mantissa(value, power, format);
+    }
+
+    let mut s = match format.digits {
+        CompactDigits::Arabic => m.to_string(),
+        CompactDigits::Chinese => from_decimal_to_chinese(number_script, method, &m)?,
+    };
+
+    if power > 0 {
+        s.push_str(unit_to_chinese(number_script, method, power).as_str());
+    }
+
+    Ok(s)
+}
+
+/// 讓整數、浮點數與 `Decimal` 能轉成縮寫寫法。
+pub trait NumberToCompact: IntoDecimal {
+    /// 將數值轉成縮寫寫法，規則請見 `from_decimal_to_compact`。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use conjak_numbers::{
+    ///     ChineseCountMethod, CompactDigits, CompactFormat, NumberScript, NumberToCompact,
+    ///     RoundingMode, ScriptStyle,
+    /// };
+    ///
+    /// let simplified = NumberScript::SimplifiedChinese(ScriptStyle::Lower { circle_as_zero: false });
+    /// let korean = NumberScript::Korean(ScriptStyle::Lower { circle_as_zero: false });
+    ///
+    /// assert_eq!("12.3万", 123456u32.to_compact(simplified, ChineseCountMethod::TenThousand, CompactFormat::default()).unwrap());
+    /// assert_eq!("3.4억", 340000000u32.to_compact(korean, ChineseCountMethod::TenThousand, CompactFormat::default()).unwrap());
+    /// assert_eq!(
+    ///     "十二点三万",
+    ///     123456u32
+    ///         .to_compact(
+    ///             simplified,
+    ///             ChineseCountMethod::TenThousand,
+    ///             CompactFormat::new(CompactDigits::Chinese, 3, RoundingMode::HalfUp)
+    ///         )
+    ///         .unwrap()
+    /// );
+    /// ```
+    fn to_compact(
+        self,
+        number_script: NumberScript,
+        method: ChineseCountMethod,
+        format: CompactFormat,
+    ) -> Result<String, NumberToChineseError> {
+        from_decimal_to_compact(number_script, method, format, &*self.into_decimal()?)
+    }
+}
+
+impl<T: IntoDecimal> NumberToCompact for T {}
@@ -0,0 +1,89 @@
+use alloc::string::{String, ToString};
+
+use super::IntoDecimal;
+use crate::{Decimal, DigitFormat, DigitGrouping, DigitWidth, NumberScript, NumberToChineseError};
+
+/// 將十進位數值轉成分組的阿拉伯數字，例如 **「123,456,789」**、**「1,2345,6789」**、**「１２３，４５６．７」**。
+///
+/// 只有整數部分會分組，小數部分照原樣寫出。數值沒有範圍的限制。
+pub fn from_decimal_to_digits(
+    number_script: NumberScript,
+    format: DigitFormat,
+    value: &Decimal,
+) -> String {
+    let group_size = match format.grouping {
+        DigitGrouping::Thousand => 3,
+        DigitGrouping::TenThousand => 4,
+        DigitGrouping::None => usize::MAX,
+    };
+
+    let separator = format.separator_for(number_script);
+
+    let mut s = String::new();
+
+    if value.is_negative() {
+        s.push(match format.width {
+            DigitWidth::Half => '-',
+            DigitWidth::Full => '－',
+        });
+    }
+
+    let integer = value.integer().to_string();
+    let length = integer.len();
+
+    for (i, b) in integer.bytes().enumerate() {
+        if i > 0 && (length - i).is_multiple_of(group_size) {
+            s.push(separator);
+        }
+
+        s.push(format.width.digit(b - b'0'));
+    }
+
+    if !value.fraction().is_empty() {
+        s.push(format.decimal_mark_for(number_script));
+
+        for &d in value.fraction() {
+            s.push(format.width.digit(d));
+        }
+    }
+
+    s
+}
+
+/// 讓整數、浮點數與 `Decimal` 能轉成分組的阿拉伯數字。
+pub trait NumberToDigits: IntoDecimal {
+    /// 將數值轉成分組的阿拉伯數字，規則請見 `from_decimal_to_digits`。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use conjak_numbers::{
+    ///     DigitFormat, DigitGrouping, DigitWidth, NumberScript, NumberToDigits, ScriptStyle,
+    /// };
+    ///
+    /// let japanese = NumberScript::Japanese(ScriptStyle::Lower { circle_as_zero: false });
+    ///
+    /// assert_eq!("123,456,789", 123456789u32.to_digits(japanese, DigitFormat::default()).unwrap());
+    /// assert_eq!(
+    ///     "1,2345,6789",
+    ///     123456789u32
+    ///         .to_digits(japanese, DigitFormat::new(DigitWidth::Half, DigitGrouping::TenThousand, None, None))
+    ///         .unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "１２，３４５．６７",
+    ///     12345.67f64
+    ///         .to_digits(japanese, DigitFormat::new(DigitWidth::Full, DigitGrouping::Thousand, None, None))
+    ///         .unwrap()
+    /// );
+    /// ```
+    fn to_digits(
+        self,
+        number_script: NumberScript,
+        format: DigitFormat,
+    ) -> Result<String, NumberToChineseError> {
+        Ok(from_decimal_to_digits(number_script, format, &*self.into_decimal()?))
+    }
+}
+
+impl<T: IntoDecimal> NumberToDigits for T {}
@@ -0,0 +1,244 @@
+use alloc::string::String;
+
+use num_bigint::BigUint;
+use num_traits::Zero;
+use serde::Deserialize;
+
+use super::{
+    check_decimal_range,
+    functions::{
+        big_unsigned_integer_to_chinese_by_method, big_unsigned_integer_to_numerals_by_method,
+        numerals_to_chinese, Numeral,
+    },
+    IntoDecimal,
+};
+use crate::{
+    chinese_characters::{ChineseExponent, ChineseNumber},
+    ChineseCountMethod, Decimal, LocaleRules, NumberScript, NumberToChineseError, RoundingMode,
+    ScriptStyle,
+};
+
+/// 金額前面標示的幣別。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AmountCurrency {
+    /// **人民币**／**人民幣**
+    Renminbi,
+    /// **新台币**／**新臺幣**
+    NewTaiwanDollar,
+    /// **港币**／**港幣**
+    HongKongDollar,
+}
+
+impl AmountCurrency {
+    #[inline]
+    pub(crate) const fn to_str(self, number_script: NumberScript) -> &'static str {
+        match self {
+            Self::Renminbi => match number_script {
+                NumberScript::SimplifiedChinese(_) => "人民币",
+                _ => "人民幣",
+            },
+            Self::NewTaiwanDollar => match number_script {
+                NumberScript::SimplifiedChinese(_) => "新台币",
+                _ => "新臺幣",
+            },
+            Self::HongKongDollar => match number_script {
+                NumberScript::SimplifiedChinese(_) => "港币",
+                _ => "港幣",
+            },
+        }
+    }
+
+    /// 金額到 **「元」** 為止時，結尾所寫的字。香港的支票習慣寫 **「正」**，其他地區寫 **「整」**。
+    #[inline]
+    const fn whole_to_str(currency: Option<Self>) -> &'static str {
+        match currency {
+            Some(Self::HongKongDollar) => "正",
+            _ => "整",
+        }
+    }
+}
+
+const YUAN: &str = "元";
+
+const WON: &str = "원";
+
+/// 韓文的契約與支票寫法，例如 **「일금 일백이십삼만원정」**、**「一金 壹百貳拾參萬원整」**。
+///
+/// 金額四捨五入到 **「원」**。大寫使用漢字的갖은자，其他樣式使用韓文。數字中間不寫 **「영」**／**「零」**，例如 **「일금 일천오원정」**，開頭是十幾時寫成 **「일십」**／**「壹拾」**。
+fn from_decimal_to_korean_financial_amount(
+    number_script: NumberScript,
+    method: ChineseCountMethod,
+    value: &Decimal,
+) -> Result<String, NumberToChineseError> {
+    let value = value.round(0, RoundingMode::HalfUp);
+
+    if value.is_negative() {
+        return Err(NumberToChineseError::Underflow);
+    }
+
+    check_decimal_range(method, &value)?;
+
+    let is_hanja = !matches!(number_script.style(), ScriptStyle::Lower { .. });
+
+    let number_script = if is_hanja {
+        number_script.with_style(ScriptStyle::Upper)
+    } else {
+        number_script.with_style(ScriptStyle::Lower {
+            circle_as_zero: false,
+        })
+    };
+
+    let mut s = String::from(if is_hanja { "一金 " } else { "일금 " });
+
+    let mut numerals = big_unsigned_integer_to_numerals_by_method(
+        LocaleRules::default(),
+        method,
+        value.integer().clone(),
+    );
+
+    // 契約金額不寫「영」，只有金額是零時才寫
+    if !value.integer().is_zero() {
+        numerals.retain(|&numeral| numeral != Numeral::Number(ChineseNumber::零));
+    }
+
+    if numerals.first() == Some(&Numeral::Exponent(ChineseExponent::十)) {
+        numerals.insert(0, Numeral::Number(ChineseNumber::一));
+    }
+
+    s.push_str(numerals_to_chinese(number_script, &numerals).as_str());
+    s.push_str(WON);
+    s.push_str(if is_hanja { "整" } else { "정" });
+
+    Ok(s)
+}
+
+/// 將十進位數值轉成票據與發票使用的中文大寫金額，例如 **「人民币壹万贰仟叁佰肆拾伍元陆角柒分」**。
+///
+/// 金額會四捨五入到 **「分」**，並依照票據填寫的規定處理 **「零」** 與 **「整」**：
+///
+/// * 數字中間有一個或連續多個零時，只寫一個 **「零」**，例如 **「壹仟零玖元」**。
+/// * **「元」** 位是零而 **「角」** 不是零，或 **「角」** 是零而 **「分」** 不是零時，在 **「元」** 後面寫 **「零」**，例如 **「壹仟陆佰捌拾元零叁角贰分」**、**「叁佰贰拾伍元零肆分」**。
+/// * 金額到 **「元」** 為止時，在後面寫 **「整」**（港幣為 **「正」**）；到 **「角」** 或 **「分」** 為止時不寫。
+/// * 開頭是十幾時寫成 **「壹拾」**，避免被加上其他數字。
+///
+/// 簡體中文與繁體中文的書寫樣式一律使用大寫。韓文改用 **「일금……원정」** 的寫法，大寫為漢字，其他樣式為韓文，且不寫幣別。其他書寫方式回傳 `UnsupportedNumberScript`。四捨五入後仍是負數的金額回傳 `Underflow`，其他數值範圍的限制與 `from_decimal_to_chinese` 相同。
+pub fn from_decimal_to_financial_amount(
+    number_script: NumberScript,
+    method: ChineseCountMethod,
+    currency: Option<AmountCurrency>,
+    value: &Decimal,
+) -> Result<String, NumberToChineseError> {
+    let number_script = match number_script {
+        NumberScript::SimplifiedChinese(_) | NumberScript::TraditionalChinese(_) => {
+            number_script.with_style(ScriptStyle::Upper)
+        },
+        NumberScript::Korean(_) => {
+            return from_decimal_to_korean_financial_amount(number_script, method, value);
+        },
+        _ => return Err(NumberToChineseError::UnsupportedNumberScript),
+    };
+
+    let value = value.round(2, RoundingMode::HalfUp);
+
+    // 票據與契約上的金額不會是負數
+    if value.is_negative() {
+        return Err(NumberToChineseError::Underflow);
+    }
+
+    check_decimal_range(method, &value)?;
+
+    let mut s = String::new();
+
+    if let Some(currency) = currency {
+        s.push_str(currency.to_str(number_script));
+    }
+
+    let integer = value.integer();
+    let jiao = value.fraction().first().copied().unwrap_or(0);
+    let fen = value.fraction().get(1).copied().unwrap_or(0);
+
+    if !integer.is_zero() || value.fraction().is_empty() {
+        let ten = ChineseExponent::十.to_str(number_script);
+        let integer_str =
+            big_unsigned_integer_to_chinese_by_method(
+                number_script,
+                LocaleRules::default(),
+                method,
+                integer.clone(),
+            );
+
+        // 票據上的「拾」前面一定要有數字
+        if integer_str.starts_with(ten) {
+            s.push_str(ChineseNumber::一.to_str(number_script));
+        }
+
+        s.push_str(integer_str.as_str());
+        s.push_str(YUAN);
+    }
+
+    if value.fraction().is_empty() {
+        s.push_str(AmountCurrency::whole_to_str(currency));
+
+        return Ok(s);
+    }
+
+    if !integer.is_zero() && (jiao == 0 || (integer % BigUint::from(10u8)).is_zero()) {
+        s.push_str(ChineseNumber::零.to_str(number_script));
+    }
+
+    if jiao > 0 {
+        s.push_str(unsafe { ChineseNumber::from_ordinal_unsafe(jiao) }.to_str(number_script));
+        s.push_str(ChineseExponent::角.to_str(number_script));
+    }
+
+    if fen > 0 {
+        s.push_str(unsafe { ChineseNumber::from_ordinal_unsafe(fen) }.to_str(number_script));
+        s.push_str(ChineseExponent::分.to_str(number_script));
+    }
+
+    Ok(s)
+}
+
+/// 讓整數、浮點數與 `Decimal` 能轉成中文大寫金額。
+pub trait NumberToFinancialAmount: IntoDecimal {
+    /// 將數值轉成中文大寫金額，規則請見 `from_decimal_to_financial_amount`。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use conjak_numbers::{
+    ///     AmountCurrency, ChineseCountMethod, NumberScript, NumberToFinancialAmount, ScriptStyle,
+    /// };
+    ///
+    /// let script = NumberScript::SimplifiedChinese(ScriptStyle::Upper);
+    ///
+    /// assert_eq!(
+    ///     "人民币壹万贰仟叁佰肆拾伍元陆角柒分",
+    ///     12345.67f64
+    ///         .to_financial_amount(script, ChineseCountMethod::TenThousand, Some(AmountCurrency::Renminbi))
+    ///         .unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "壹拾万柒仟元整",
+    ///     107000u32.to_financial_amount(script, ChineseCountMethod::TenThousand, None).unwrap()
+    /// );
+    ///
+    /// let korean = NumberScript::Korean(ScriptStyle::Lower { circle_as_zero: false });
+    ///
+    /// assert_eq!(
+    ///     "일금 일백이십삼만원정",
+    ///     1230000u32.to_financial_amount(korean, ChineseCountMethod::TenThousand, None).unwrap()
+    /// );
+    /// ```
+    fn to_financial_amount(
+        self,
+        number_script: NumberScript,
+        method: ChineseCountMethod,
+        currency: Option<AmountCurrency>,
+    ) -> Result<String, NumberToChineseError> {
+        from_decimal_to_financial_amount(number_script, method, currency, &*self.into_decimal()?)
+    }
+}
+
+impl<T: IntoDecimal> NumberToFinancialAmount for T {}
@@ -1,50 +1,175 @@
-use alloc::string::{String, ToString};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use num_bigint::BigUint;
 #[allow(unused_imports)]
 use num_traits::float::FloatCore;
 use num_traits::{FromPrimitive, ToPrimitive, Zero};
 
-use crate::{ChineseCountMethod, ChineseExponent, ChineseNumber, NumberScript};
+use crate::{
+    ChineseCountMethod, ChineseExponent, ChineseFractionUnit, ChineseNumber, ChinesePoint,
+    ChineseSign, FractionMode, LocaleRules, MixedFormat, NumberScript, ScriptStyle, SpacingFormat,
+    TenSokuon, TwoForm,
+};
+
+/// 中文數字的一個字。數字先組成一串 `Numeral`，再依照書寫方式寫成文字，或讀成假名、拼音。
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum Numeral {
+    Number(ChineseNumber),
+    /// 數字開頭的 **「2」** 依照 `LocaleRules` 寫成 **「兩」**。只有小寫的中文會寫成 **「兩」**（**「两」**），其他書寫方式仍寫 **「二」**。
+    Liang,
+    Exponent(ChineseExponent),
+    FractionUnit(ChineseFractionUnit),
+    Point,
+    Sign(ChineseSign),
+}
 
-pub(crate) fn unsigned_integer_to_chinese_low(
-    chinese_variant: NumberScript,
+/// 只有小寫的中文會把 **「2」** 寫成 **「兩」**。
+#[inline]
+pub(crate) const fn writes_liang(chinese_variant: NumberScript) -> bool {
+    matches!(
+        chinese_variant,
+        NumberScript::SimplifiedChinese(ScriptStyle::Lower { .. })
+            | NumberScript::TraditionalChinese(ScriptStyle::Lower { .. })
+    )
+}
+
+impl Numeral {
+    #[inline]
+    pub(crate) fn to_str(self, chinese_variant: NumberScript) -> &'static str {
+        match self {
+            Self::Number(number) => number.to_str(chinese_variant),
+            Self::Liang => match chinese_variant {
+                NumberScript::SimplifiedChinese(ScriptStyle::Lower { .. }) => "两",
+                NumberScript::TraditionalChinese(ScriptStyle::Lower { .. }) => "兩",
+                _ => ChineseNumber::二.to_str(chinese_variant),
+            },
+            Self::Exponent(exponent) => exponent.to_str(chinese_variant),
+            Self::FractionUnit(unit) => unit.to_str(chinese_variant),
+            Self::Point => ChinesePoint::to_str(chinese_variant),
+            Self::Sign(sign) => sign.to_str(chinese_variant),
+        }
+    }
+}
+
+/// 依照書寫方式把一串 `Numeral` 寫成文字。
+pub(crate) fn numerals_to_chinese(chinese_variant: NumberScript, numerals: &[Numeral]) -> String {
+    let mut s = String::new();
+
+    for numeral in numerals {
+        s.push_str(numeral.to_str(chinese_variant));
+    }
+
+    s
+}
+
+/// 寫在 `exponent` 前面的一位數字。數字開頭的 **「一」**、**「二」** 依照 `rules` 省略或寫成 **「兩」**。
+fn digit_before_exponent(
+    rules: LocaleRules,
+    leading: bool,
+    d: u8,
+    exponent: ChineseExponent,
+) -> Option<Numeral> {
+    let write_one = match exponent {
+        ChineseExponent::十 => {
+            if leading {
+                rules.one_before_leading_ten
+            } else {
+                rules.one_before_inner_ten
+            }
+        },
+        ChineseExponent::百 | ChineseExponent::千 => rules.one_before_hundred_and_thousand,
+        _ => !leading || rules.one_before_myriad,
+    };
+
+    let use_liang = leading
+        && match exponent {
+            ChineseExponent::十 => false,
+            ChineseExponent::百 => rules.two == TwoForm::BeforeHundred,
+            _ => rules.two != TwoForm::Er,
+        };
+
+    match d {
+        1 if !write_one => None,
+        2 if use_liang => Some(Numeral::Liang),
+        _ => Some(Numeral::Number(unsafe { ChineseNumber::from_ordinal_unsafe(d) })),
+    }
+}
+
+/// 數字開頭、寫在 **「萬」** 以上的單位前面的一組數字。只有 **「一」** 或 **「二」** 時依照 `rules` 省略或寫成 **「兩」**，否則回傳 `None`。`d` 為 `None` 表示超過 `u128` 的數值。
+fn leading_group(rules: LocaleRules, dependent: bool, d: Option<u128>) -> Option<&'static [Numeral]> {
+    if dependent {
+        return None;
+    }
+
+    match d {
+        Some(1) if !rules.one_before_myriad => Some(&[]),
+        Some(2) if rules.two != TwoForm::Er => Some(&[Numeral::Liang]),
+        _ => None,
+    }
+}
+
+/// 把數值由低位到高位分成每四位數一組，數值為零時只有一組。
+pub(crate) fn ten_thousand_groups(mut value: BigUint) -> Vec<u16> {
+    let big_1_0000 = BigUint::from(1_0000u16);
+
+    let mut groups = Vec::new();
+
+    loop {
+        groups.push((value.clone() % &big_1_0000).to_u16().unwrap());
+        value /= &big_1_0000;
+
+        if value.is_zero() {
+            break;
+        }
+    }
+
+    groups
+}
+
+/// 使用 **「萬進」** 時，由低位算起第 `i` 組四位數後面的單位，例如 `1` 為 **「萬」**、`2` 為 **「億」**。
+#[inline]
+pub(crate) fn myriad_exponent(i: usize) -> ChineseExponent {
+    debug_assert!(i > 0 && i < 13);
+
+    unsafe { ChineseExponent::from_ordinal_unsafe(ChineseExponent::萬.ordinal() + i as u8 - 1) }
+}
+
+pub(crate) fn unsigned_integer_to_numerals_low(
+    rules: LocaleRules,
     dependent: bool,
     mut value: u128,
-) -> String {
+) -> Vec<Numeral> {
     debug_assert!(value < 1_0000_0000_0000_0000);
 
-    let mut s = String::new();
+    let mut numerals = Vec::new();
 
     let mut lower_d = (value % 10) as u8;
     value /= 10;
 
     if lower_d > 0 {
-        s.push_str(
-            unsafe { ChineseNumber::from_ordinal_unsafe(lower_d) }
-                .to_str(chinese_variant),
-        );
+        numerals.push(Numeral::Number(unsafe { ChineseNumber::from_ordinal_unsafe(lower_d) }));
     } else if value == 0 {
-        return ChineseNumber::零.to_str(chinese_variant).to_string();
+        return vec![Numeral::Number(ChineseNumber::零)];
     }
 
     let d = (value % 10) as u8;
     value /= 10;
 
     if d > 0 {
-        s.insert_str(0, ChineseExponent::十.to_str(chinese_variant));
-
-        if value > 0 || dependent || d > 1 {
-            s.insert_str(
-                0,
-                unsafe { ChineseNumber::from_ordinal_unsafe(d) }
-                    .to_str(chinese_variant),
-            );
+        numerals.insert(0, Numeral::Exponent(ChineseExponent::十));
+
+        if let Some(numeral) =
+            digit_before_exponent(rules, value == 0 && !dependent, d, ChineseExponent::十)
+        {
+            numerals.insert(0, numeral);
         }
     }
 
     if value == 0 {
-        return s;
+        return numerals;
     }
 
     lower_d = d;
@@ -56,21 +181,19 @@ pub(crate) fn unsigned_integer_to_chinese_low(
         value /= 10;
 
         if d > 0 {
-            if lower_d < 1 && !s.is_empty() {
-                s.insert_str(0, ChineseNumber::零.to_str(chinese_variant));
+            if lower_d < 1 && !numerals.is_empty() {
+                numerals.insert(0, Numeral::Number(ChineseNumber::零));
             }
 
-            s.insert_str(
-                0,
-                unsafe { ChineseExponent::from_ordinal_unsafe(i) }
-                    .to_str(chinese_variant),
-            );
-
-            s.insert_str(
-                0,
-                unsafe { ChineseNumber::from_ordinal_unsafe(d) }
-                    .to_str(chinese_variant),
-            );
+            let exponent = unsafe { ChineseExponent::from_ordinal_unsafe(i) };
+
+            numerals.insert(0, Numeral::Exponent(exponent));
+
+            if let Some(numeral) =
+                digit_before_exponent(rules, value == 0 && !dependent, d, exponent)
+            {
+                numerals.insert(0, numeral);
+            }
         }
 
         if value == 0 {
@@ -82,174 +205,152 @@ pub(crate) fn unsigned_integer_to_chinese_low(
         i += 1;
     }
 
-    s
+    numerals
 }
 
-pub(crate) fn unsigned_integer_to_chinese_ten_thousand(
-    chinese_variant: NumberScript,
+#[inline]
+pub(crate) fn unsigned_integer_to_numerals_ten_thousand(
+    rules: LocaleRules,
     dependent: bool,
-    mut value: u128,
-) -> String {
-    let mut lower_d = value % 1_0000;
-    value /= 1_0000;
+    value: u128,
+) -> Vec<Numeral> {
+    big_unsigned_integer_to_numerals_ten_thousand(rules, dependent, BigUint::from(value))
+}
 
-    let mut has_more = value > 0;
+pub(crate) fn big_unsigned_integer_to_numerals_ten_thousand(
+    rules: LocaleRules,
+    dependent: bool,
+    value: BigUint,
+) -> Vec<Numeral> {
+    big_unsigned_integer_to_numeral_groups_ten_thousand(rules, dependent, SpacingFormat::default(), value)
+        .concat()
+}
 
-    let mut s = if lower_d > 0 {
-        unsigned_integer_to_chinese_low(
-            chinese_variant,
-            dependent || has_more,
-            lower_d,
-        )
-    } else if value == 0 {
-        return ChineseNumber::零.to_str(chinese_variant).to_string();
-    } else {
-        String::new()
-    };
+/// 使用 **「萬進」**，依照 `spacing` 把數字分成以萬進單位結尾的數組，數組之間的 **「零」** 寫在後一組的開頭，例如 **「一亿」**、**「零五」**。分隔符號由呼叫者寫在數組之間。
+///
+/// `spacing.omit_leading_one` 省略 **「十」**、**「百」**、**「千」** 與 **「萬」** 前面單獨的 **「一」**，但開頭是 **「零」** 的數組不省略。`spacing.omit_zero` 省略整數部分所有的 **「零」**，數值為零時除外。
+pub(crate) fn big_unsigned_integer_to_numeral_groups_ten_thousand(
+    rules: LocaleRules,
+    dependent: bool,
+    spacing: SpacingFormat,
+    value: BigUint,
+) -> Vec<Vec<Numeral>> {
+    debug_assert!(value < BigUint::from(10u8).pow(52));
 
-    if !has_more {
-        return s;
+    let groups = ten_thousand_groups(value);
+    let top = groups.len() - 1;
+
+    if groups[top] == 0 {
+        return vec![vec![Numeral::Number(ChineseNumber::零)]];
     }
 
-    let mut i = ChineseExponent::萬.ordinal();
+    let omit_one_rules = LocaleRules {
+        one_before_leading_ten: false,
+        one_before_inner_ten: false,
+        one_before_hundred_and_thousand: false,
+        ..rules
+    };
+
+    let mut numeral_groups: Vec<Vec<Numeral>> = Vec::new();
 
-    loop {
-        let d = value % 1_0000;
-        value /= 1_0000;
+    for (i, &d) in groups.iter().enumerate().rev() {
+        if d == 0 {
+            continue;
+        }
 
-        has_more = value > 0;
+        let dependent = dependent || i < top;
 
-        if d > 0 {
-            if lower_d < 1000 && !s.is_empty() {
-                s.insert_str(0, ChineseNumber::零.to_str(chinese_variant));
-            }
+        let mut numerals = Vec::new();
 
-            s.insert_str(
-                0,
-                unsafe { ChineseExponent::from_ordinal_unsafe(i) }
-                    .to_str(chinese_variant),
-            );
-
-            s.insert_str(
-                0,
-                unsigned_integer_to_chinese_low(
-                    chinese_variant,
-                    dependent || has_more,
-                    d,
-                )
-                .as_str(),
-            );
+        if !numeral_groups.is_empty() && (d < 1000 || groups[i + 1] == 0) && !spacing.omit_zero {
+            numerals.push(Numeral::Number(ChineseNumber::零));
         }
 
-        if !has_more {
-            break;
+        // 「零」後面的數組仍寫「一」
+        let omit_one = spacing.omit_leading_one && numerals.is_empty();
+        let rules = if omit_one { omit_one_rules } else { rules };
+
+        if i == 0 {
+            numerals.extend(unsigned_integer_to_numerals_low(rules, dependent, d as u128));
+        } else {
+            if !(omit_one && i == 1 && d == 1) {
+                match leading_group(rules, dependent, Some(d as u128)) {
+                    Some(group) => numerals.extend_from_slice(group),
+                    None => numerals.extend(unsigned_integer_to_numerals_low(rules, dependent, d as u128)),
+                }
+            }
+
+            numerals.push(Numeral::Exponent(myriad_exponent(i)));
         }
 
-        lower_d = d;
+        if spacing.omit_zero {
+            numerals.retain(|&numeral| numeral != Numeral::Number(ChineseNumber::零));
+        }
 
-        i += 1;
+        numeral_groups.push(numerals);
     }
 
-    s
+    numeral_groups
 }
 
-pub(crate) fn big_unsigned_integer_to_chinese_ten_thousand(
+/// 使用 **「萬進」**，每一組四位數以阿拉伯數字寫出，只有大單位使用中文，例如 **「1億2345万6789」**。韓文在每個單位後面加上空白，例如 **「12억 3456만 7898」**。
+pub(crate) fn big_unsigned_integer_to_mixed_ten_thousand(
     chinese_variant: NumberScript,
-    dependent: bool,
-    mut value: BigUint,
+    format: MixedFormat,
+    value: BigUint,
 ) -> String {
     debug_assert!(value < BigUint::from(10u8).pow(52));
 
-    let big_0 = BigUint::zero();
-    let big_1_0000 = BigUint::from(1_0000u16);
-
-    let mut lower_d = (value.clone() % &big_1_0000).to_u128().unwrap();
-    value /= &big_1_0000;
-
-    let mut has_more = value > big_0;
+    let groups = ten_thousand_groups(value);
 
-    let mut s = if lower_d > 0 {
-        unsigned_integer_to_chinese_low(
-            chinese_variant,
-            dependent || has_more,
-            lower_d,
-        )
-    } else if value == big_0 {
-        return ChineseNumber::零.to_str(chinese_variant).to_string();
-    } else {
-        String::new()
+    let separator = match chinese_variant {
+        NumberScript::Korean(_) => " ",
+        _ => "",
     };
 
-    if !has_more {
-        return s;
-    }
-
-    let mut i = ChineseExponent::萬.ordinal();
-
-    loop {
-        let d = (value.clone() % &big_1_0000).to_u128().unwrap();
-        value /= &big_1_0000;
-
-        has_more = value > big_0;
-
-        if d > 0 {
-            if lower_d < 1000 && !s.is_empty() {
-                s.insert_str(0, ChineseNumber::零.to_str(chinese_variant));
-            }
+    let mut s = String::new();
 
-            s.insert_str(
-                0,
-                unsafe { ChineseExponent::from_ordinal_unsafe(i) }
-                    .to_str(chinese_variant),
-            );
-
-            s.insert_str(
-                0,
-                unsigned_integer_to_chinese_low(
-                    chinese_variant,
-                    dependent || has_more,
-                    d,
-                )
-                .as_str(),
-            );
+    for (i, &d) in groups.iter().enumerate().rev() {
+        if d == 0 && format.omit_empty_groups && !(i == 0 && s.is_empty()) {
+            continue;
         }
 
-        if !has_more {
-            break;
+        if !s.is_empty() {
+            s.push_str(separator);
         }
 
-        lower_d = d;
+        for b in d.to_string().bytes() {
+            s.push(format.width.digit(b - b'0'));
+        }
 
-        i += 1;
+        if i > 0 {
+            s.push_str(myriad_exponent(i).to_str(chinese_variant));
+        }
     }
 
     s
 }
 
-pub(crate) fn unsigned_integer_to_chinese_middle(
-    chinese_variant: NumberScript,
+pub(crate) fn unsigned_integer_to_numerals_middle(
+    rules: LocaleRules,
     dependent: bool,
     mut value: u128,
-) -> String {
+) -> Vec<Numeral> {
     let mut lower_d = value % 1_0000_0000;
     value /= 1_0000_0000;
 
     let mut has_more = value > 0;
 
-    let mut s = if lower_d > 0 {
-        unsigned_integer_to_chinese_ten_thousand(
-            chinese_variant,
-            dependent || has_more,
-            lower_d,
-        )
+    let mut numerals = if lower_d > 0 {
+        unsigned_integer_to_numerals_ten_thousand(rules, dependent || has_more, lower_d)
     } else if value == 0 {
-        return ChineseNumber::零.to_str(chinese_variant).to_string();
+        return vec![Numeral::Number(ChineseNumber::零)];
     } else {
-        String::new()
+        Vec::new()
     };
 
     if !has_more {
-        return s;
+        return numerals;
     }
 
     let mut i = ChineseExponent::億.ordinal();
@@ -261,25 +362,23 @@ pub(crate) fn unsigned_integer_to_chinese_middle(
         has_more = value > 0;
 
         if d > 0 {
-            if lower_d < 1000_0000 && !s.is_empty() {
-                s.insert_str(0, ChineseNumber::零.to_str(chinese_variant));
+            if lower_d < 1000_0000 && !numerals.is_empty() {
+                numerals.insert(0, Numeral::Number(ChineseNumber::零));
             }
 
-            s.insert_str(
-                0,
-                unsafe { ChineseExponent::from_ordinal_unsafe(i) }
-                    .to_str(chinese_variant),
-            );
-
-            s.insert_str(
-                0,
-                unsigned_integer_to_chinese_ten_thousand(
-                    chinese_variant,
-                    dependent || has_more,
-                    d,
-                )
-                .as_str(),
-            );
+            numerals.insert(0, Numeral::Exponent(unsafe { ChineseExponent::from_ordinal_unsafe(i) }));
+
+            match leading_group(rules, dependent || has_more, Some(d)) {
+                Some(group) => {
+                    numerals.splice(0..0, group.iter().copied());
+                },
+                None => {
+                    numerals.splice(
+                        0..0,
+                        unsigned_integer_to_numerals_ten_thousand(rules, dependent || has_more, d),
+                    );
+                },
+            }
         }
 
         if !has_more {
@@ -291,14 +390,14 @@ pub(crate) fn unsigned_integer_to_chinese_middle(
         i += 1;
     }
 
-    s
+    numerals
 }
 
-pub(crate) fn big_unsigned_integer_to_chinese_middle(
-    chinese_variant: NumberScript,
+pub(crate) fn big_unsigned_integer_to_numerals_middle(
+    rules: LocaleRules,
     dependent: bool,
     mut value: BigUint,
-) -> String {
+) -> Vec<Numeral> {
     debug_assert!(value < BigUint::from(10u8).pow(96));
 
     let big_0 = BigUint::zero();
@@ -309,20 +408,16 @@ pub(crate) fn big_unsigned_integer_to_chinese_middle(
 
     let mut has_more = value > big_0;
 
-    let mut s = if lower_d > 0 {
-        unsigned_integer_to_chinese_ten_thousand(
-            chinese_variant,
-            dependent || has_more,
-            lower_d,
-        )
+    let mut numerals = if lower_d > 0 {
+        unsigned_integer_to_numerals_ten_thousand(rules, dependent || has_more, lower_d)
     } else if value == big_0 {
-        return ChineseNumber::零.to_str(chinese_variant).to_string();
+        return vec![Numeral::Number(ChineseNumber::零)];
     } else {
-        String::new()
+        Vec::new()
     };
 
     if !has_more {
-        return s;
+        return numerals;
     }
 
     let mut i = ChineseExponent::億.ordinal();
@@ -334,25 +429,23 @@ pub(crate) fn big_unsigned_integer_to_chinese_middle(
         has_more = value > big_0;
 
         if d > 0 {
-            if lower_d < 1000_0000 && !s.is_empty() {
-                s.insert_str(0, ChineseNumber::零.to_str(chinese_variant));
+            if lower_d < 1000_0000 && !numerals.is_empty() {
+                numerals.insert(0, Numeral::Number(ChineseNumber::零));
             }
 
-            s.insert_str(
-                0,
-                unsafe { ChineseExponent::from_ordinal_unsafe(i) }
-                    .to_str(chinese_variant),
-            );
-
-            s.insert_str(
-                0,
-                unsigned_integer_to_chinese_ten_thousand(
-                    chinese_variant,
-                    dependent || has_more,
-                    d,
-                )
-                .as_str(),
-            );
+            numerals.insert(0, Numeral::Exponent(unsafe { ChineseExponent::from_ordinal_unsafe(i) }));
+
+            match leading_group(rules, dependent || has_more, Some(d)) {
+                Some(group) => {
+                    numerals.splice(0..0, group.iter().copied());
+                },
+                None => {
+                    numerals.splice(
+                        0..0,
+                        unsigned_integer_to_numerals_ten_thousand(rules, dependent || has_more, d),
+                    );
+                },
+            }
         }
 
         if !has_more {
@@ -364,14 +457,14 @@ pub(crate) fn big_unsigned_integer_to_chinese_middle(
         i += 1;
     }
 
-    s
+    numerals
 }
 
-pub(crate) fn unsigned_integer_to_chinese_high(
-    chinese_variant: NumberScript,
+pub(crate) fn unsigned_integer_to_numerals_high(
+    rules: LocaleRules,
     dependent: bool,
     mut value: u128,
-) -> String {
+) -> Vec<Numeral> {
     let mut w = 1_0000_0000_0000_0000;
 
     let mut lower_d = value % w;
@@ -379,20 +472,16 @@ pub(crate) fn unsigned_integer_to_chinese_high(
 
     let mut has_more = value > 0;
 
-    let mut s = if lower_d > 0 {
-        unsigned_integer_to_chinese_middle(
-            chinese_variant,
-            dependent || has_more,
-            lower_d,
-        )
+    let mut numerals = if lower_d > 0 {
+        unsigned_integer_to_numerals_middle(rules, dependent || has_more, lower_d)
     } else if value == 0 {
-        return ChineseNumber::零.to_str(chinese_variant).to_string();
+        return vec![Numeral::Number(ChineseNumber::零)];
     } else {
-        String::new()
+        Vec::new()
     };
 
     if !has_more {
-        return s;
+        return numerals;
     }
 
     let mut i = ChineseExponent::兆.ordinal();
@@ -406,25 +495,23 @@ pub(crate) fn unsigned_integer_to_chinese_high(
         has_more = value > 0;
 
         if d > 0 {
-            if lower_d < previous_w / 10 && !s.is_empty() {
-                s.insert_str(0, ChineseNumber::零.to_str(chinese_variant));
+            if lower_d < previous_w / 10 && !numerals.is_empty() {
+                numerals.insert(0, Numeral::Number(ChineseNumber::零));
             }
 
-            s.insert_str(
-                0,
-                unsafe { ChineseExponent::from_ordinal_unsafe(i) }
-                    .to_str(chinese_variant),
-            );
-
-            s.insert_str(
-                0,
-                unsigned_integer_to_chinese_high(
-                    chinese_variant,
-                    dependent || has_more,
-                    d,
-                )
-                .as_str(),
-            );
+            numerals.insert(0, Numeral::Exponent(unsafe { ChineseExponent::from_ordinal_unsafe(i) }));
+
+            match leading_group(rules, dependent || has_more, Some(d)) {
+                Some(group) => {
+                    numerals.splice(0..0, group.iter().copied());
+                },
+                None => {
+                    numerals.splice(
+                        0..0,
+                        unsigned_integer_to_numerals_high(rules, dependent || has_more, d),
+                    );
+                },
+            }
         }
 
         if !has_more {
@@ -439,14 +526,14 @@ pub(crate) fn unsigned_integer_to_chinese_high(
         w *= w;
     }
 
-    s
+    numerals
 }
 
-pub(crate) fn big_unsigned_integer_to_chinese_high(
-    chinese_variant: NumberScript,
+pub(crate) fn big_unsigned_integer_to_numerals_high(
+    rules: LocaleRules,
     dependent: bool,
     mut value: BigUint,
-) -> String {
+) -> Vec<Numeral> {
     let big_0 = BigUint::zero();
     let big_10 = BigUint::from(10u8);
 
@@ -457,20 +544,16 @@ pub(crate) fn big_unsigned_integer_to_chinese_high(
 
     let mut has_more = value > big_0;
 
-    let mut s = if lower_d > big_0 {
-        unsigned_integer_to_chinese_middle(
-            chinese_variant,
-            dependent || has_more,
-            lower_d.to_u128().unwrap(),
-        )
+    let mut numerals = if lower_d > big_0 {
+        unsigned_integer_to_numerals_middle(rules, dependent || has_more, lower_d.to_u128().unwrap())
     } else if value == big_0 {
-        return ChineseNumber::零.to_str(chinese_variant).to_string();
+        return vec![Numeral::Number(ChineseNumber::零)];
     } else {
-        String::new()
+        Vec::new()
     };
 
     if !has_more {
-        return s;
+        return numerals;
     }
 
     let mut i = ChineseExponent::兆.ordinal();
@@ -484,25 +567,23 @@ pub(crate) fn big_unsigned_integer_to_chinese_high(
         has_more = value > big_0;
 
         if d > big_0 {
-            if lower_d < previous_w / &big_10 && !s.is_empty() {
-                s.insert_str(0, ChineseNumber::零.to_str(chinese_variant));
+            if lower_d < previous_w / &big_10 && !numerals.is_empty() {
+                numerals.insert(0, Numeral::Number(ChineseNumber::零));
             }
 
-            s.insert_str(
-                0,
-                unsafe { ChineseExponent::from_ordinal_unsafe(i) }
-                    .to_str(chinese_variant),
-            );
-
-            s.insert_str(
-                0,
-                big_unsigned_integer_to_chinese_high(
-                    chinese_variant,
-                    dependent || has_more,
-                    d.clone(),
-                )
-                .as_str(),
-            );
+            numerals.insert(0, Numeral::Exponent(unsafe { ChineseExponent::from_ordinal_unsafe(i) }));
+
+            match leading_group(rules, dependent || has_more, d.to_u128()) {
+                Some(group) => {
+                    numerals.splice(0..0, group.iter().copied());
+                },
+                None => {
+                    numerals.splice(
+                        0..0,
+                        big_unsigned_integer_to_numerals_high(rules, dependent || has_more, d.clone()),
+                    );
+                },
+            }
         }
 
         if !has_more {
@@ -517,14 +598,107 @@ pub(crate) fn big_unsigned_integer_to_chinese_high(
         w = w.clone() * w;
     }
 
-    s
+    numerals
+}
+
+/// 以小數點逐位讀出小數部分，例如 **「點一二五」**。
+pub(crate) fn fraction_digits_to_numerals(fraction: &[u8]) -> Vec<Numeral> {
+    let mut numerals = Vec::with_capacity(fraction.len() + 1);
+
+    numerals.push(Numeral::Point);
+
+    for &d in fraction {
+        numerals.push(Numeral::Number(unsafe { ChineseNumber::from_ordinal_unsafe(d) }));
+    }
+
+    numerals
 }
 
-pub(crate) fn positive_float_to_chinese(
+#[inline]
+pub(crate) fn fraction_digits_to_chinese(
     chinese_variant: NumberScript,
+    fraction: &[u8],
+) -> String {
+    numerals_to_chinese(chinese_variant, &fraction_digits_to_numerals(fraction))
+}
+
+#[inline]
+fn fraction_unit(chinese_variant: NumberScript, mode: FractionMode, i: usize) -> Numeral {
+    match mode {
+        FractionMode::Currency => match i {
+            0 => Numeral::Exponent(ChineseExponent::角),
+            1 => Numeral::Exponent(ChineseExponent::分),
+            // 日文的「角」、「分」已經寫成「銭」、「厘」，下一級是「毛」
+            _ => match chinese_variant {
+                NumberScript::Japanese(_) => Numeral::FractionUnit(ChineseFractionUnit::毫),
+                _ => Numeral::FractionUnit(ChineseFractionUnit::釐),
+            },
+        },
+        _ => Numeral::FractionUnit(unsafe { ChineseFractionUnit::from_ordinal_unsafe(i as u8) }),
+    }
+}
+
+/// 以單位讀出小數部分，為零的位數直接略過，例如 **「三角五釐」**。
+pub(crate) fn fraction_units_to_numerals(
+    chinese_variant: NumberScript,
+    mode: FractionMode,
+    fraction: &[u8],
+) -> Vec<Numeral> {
+    debug_assert!(mode != FractionMode::DecimalPoint);
+    debug_assert!(fraction.len() <= mode.max_precision());
+
+    let mut numerals = Vec::new();
+
+    for (i, &d) in fraction.iter().enumerate() {
+        if d > 0 {
+            numerals.push(Numeral::Number(unsafe { ChineseNumber::from_ordinal_unsafe(d) }));
+            numerals.push(fraction_unit(chinese_variant, mode, i));
+        }
+    }
+
+    numerals
+}
+
+#[inline]
+pub(crate) fn fraction_units_to_chinese(
+    chinese_variant: NumberScript,
+    mode: FractionMode,
+    fraction: &[u8],
+) -> String {
+    numerals_to_chinese(chinese_variant, &fraction_units_to_numerals(chinese_variant, mode, fraction))
+}
+
+pub(crate) fn big_unsigned_integer_to_numerals_by_method(
+    rules: LocaleRules,
     method: ChineseCountMethod,
-    value: f64,
+    value: BigUint,
+) -> Vec<Numeral> {
+    match method {
+        ChineseCountMethod::Low => {
+            unsigned_integer_to_numerals_low(rules, false, value.to_u128().unwrap())
+        },
+        ChineseCountMethod::TenThousand => {
+            big_unsigned_integer_to_numerals_ten_thousand(rules, false, value)
+        },
+        ChineseCountMethod::Middle => big_unsigned_integer_to_numerals_middle(rules, false, value),
+        ChineseCountMethod::High => big_unsigned_integer_to_numerals_high(rules, false, value),
+    }
+}
+
+#[inline]
+pub(crate) fn big_unsigned_integer_to_chinese_by_method(
+    chinese_variant: NumberScript,
+    rules: LocaleRules,
+    method: ChineseCountMethod,
+    value: BigUint,
 ) -> String {
+    numerals_to_chinese(
+        chinese_variant,
+        &big_unsigned_integer_to_numerals_by_method(rules, method, value),
+    )
+}
+
+pub(crate) fn positive_float_to_numerals(method: ChineseCountMethod, value: f64) -> Vec<Numeral> {
     let (integer, fraction) = {
         let integer = BigUint::from_f64(value.trunc()).unwrap();
         let fraction = ((value.fract() * 100.0).round() % 100f64) as u8;
@@ -534,62 +708,121 @@ pub(crate) fn positive_float_to_chinese(
 
     let big_0 = BigUint::zero();
 
-    let mut s = if integer > big_0 {
-        match method {
-            ChineseCountMethod::Low => unsigned_integer_to_chinese_low(
-                chinese_variant,
-                false,
-                integer.to_u128().unwrap(),
-            ),
-            ChineseCountMethod::TenThousand => big_unsigned_integer_to_chinese_ten_thousand(
-                chinese_variant,
-                false,
-                integer.clone(),
-            ),
-            ChineseCountMethod::Middle => big_unsigned_integer_to_chinese_middle(
-                chinese_variant,
-                false,
-                integer.clone(),
-            ),
-            ChineseCountMethod::High => big_unsigned_integer_to_chinese_high(
-                chinese_variant,
-                false,
-                integer.clone(),
-            ),
-        }
+    let mut numerals = if integer > big_0 {
+        big_unsigned_integer_to_numerals_by_method(LocaleRules::default(), method, integer.clone())
     } else {
-        String::new()
+        Vec::new()
     };
 
     if fraction >= 10 {
         let msd = fraction / 10;
         let lsd = fraction % 10;
 
-        s.push_str(
-            unsafe { ChineseNumber::from_ordinal_unsafe(msd) }
-                .to_str(chinese_variant),
-        );
-
-        s.push_str(ChineseExponent::角.to_str(chinese_variant));
+        numerals.push(Numeral::Number(unsafe { ChineseNumber::from_ordinal_unsafe(msd) }));
+        numerals.push(Numeral::Exponent(ChineseExponent::角));
 
         if lsd > 0 {
-            s.push_str(
-                unsafe { ChineseNumber::from_ordinal_unsafe(lsd) }
-                    .to_str(chinese_variant),
-            );
-
-            s.push_str(ChineseExponent::分.to_str(chinese_variant));
+            numerals.push(Numeral::Number(unsafe { ChineseNumber::from_ordinal_unsafe(lsd) }));
+            numerals.push(Numeral::Exponent(ChineseExponent::分));
         }
     } else if fraction >= 1 {
-        s.push_str(
-            unsafe { ChineseNumber::from_ordinal_unsafe(fraction) }
-                .to_str(chinese_variant),
-        );
-
-        s.push_str(ChineseExponent::分.to_str(chinese_variant));
+        numerals.push(Numeral::Number(unsafe { ChineseNumber::from_ordinal_unsafe(fraction) }));
+        numerals.push(Numeral::Exponent(ChineseExponent::分));
     } else if integer == big_0 {
-        s.push_str(ChineseNumber::零.to_str(chinese_variant));
+        numerals.push(Numeral::Number(ChineseNumber::零));
     }
 
-    s
+    numerals
+}
+
+/// 單位以カ行、サ行、タ行開頭時，前面的 **「いち」**、**「はち」**、**「じゅう」** 等會變成促音，例如 **「いっちょう」**、**「はっけい」**、**「ひゃっけい」**。
+fn japanese_kana_sokuon(
+    segment: &'static str,
+    unit: ChineseExponent,
+    ten_sokuon: TenSokuon,
+) -> &'static str {
+    let k_row = matches!(unit, ChineseExponent::京 | ChineseExponent::溝 | ChineseExponent::澗);
+    let st_row = matches!(unit, ChineseExponent::兆 | ChineseExponent::正 | ChineseExponent::載);
+
+    if !k_row && !st_row {
+        return segment;
+    }
+
+    match segment {
+        "いち" => "いっ",
+        "はち" => "はっ",
+        "じゅう" => match ten_sokuon {
+            TenSokuon::Ju => "じゅっ",
+            TenSokuon::Ji => "じっ",
+        },
+        "ろく" if k_row => "ろっ",
+        "ひゃく" if k_row => "ひゃっ",
+        "びゃく" if k_row => "びゃっ",
+        "ぴゃく" if k_row => "ぴゃっ",
+        _ => segment,
+    }
+}
+
+/// 讀出數字的平假名，例如 **「いっせんまん」**、**「さんびゃくおく」**、**「マイナスさんてんにご」**。`numerals` 須依照日本的寫法（`LocalePreset::Jp`）組成，也就是 **「十」**、**「百」**、**「千」** 前面不寫 **「一」**。
+pub(crate) fn numerals_to_japanese_kana(numerals: &[Numeral], ten_sokuon: TenSokuon) -> String {
+    let mut segments: Vec<&'static str> = Vec::with_capacity(numerals.len());
+
+    let mut after_point = false;
+
+    for (i, &numeral) in numerals.iter().enumerate() {
+        let previous = if i > 0 { Some(numerals[i - 1]) } else { None };
+
+        match numeral {
+            // 整數中間的「零」不讀出來
+            Numeral::Number(ChineseNumber::零)
+                if !after_point
+                    && matches!(previous, Some(Numeral::Number(_) | Numeral::Exponent(_))) => {},
+            Numeral::Number(number) => segments.push(number.to_kana()),
+            Numeral::Liang => segments.push(ChineseNumber::二.to_kana()),
+            Numeral::Exponent(ChineseExponent::千) => match previous {
+                Some(Numeral::Number(ChineseNumber::三)) => segments.push("ぜん"),
+                Some(Numeral::Number(ChineseNumber::八)) => {
+                    *segments.last_mut().unwrap() = "はっ";
+                    segments.push("せん");
+                },
+                Some(Numeral::Number(_) | Numeral::Liang) => segments.push("せん"),
+                // 「千万」、「千億」讀作「いっせん」
+                _ => match numerals.get(i + 1) {
+                    Some(Numeral::Exponent(exponent)) if *exponent >= ChineseExponent::萬 => {
+                        segments.push("いっせん")
+                    },
+                    _ => segments.push("せん"),
+                },
+            },
+            Numeral::Exponent(ChineseExponent::百) => match previous {
+                Some(Numeral::Number(ChineseNumber::三)) => segments.push("びゃく"),
+                Some(Numeral::Number(ChineseNumber::六)) => {
+                    *segments.last_mut().unwrap() = "ろっ";
+                    segments.push("ぴゃく");
+                },
+                Some(Numeral::Number(ChineseNumber::八)) => {
+                    *segments.last_mut().unwrap() = "はっ";
+                    segments.push("ぴゃく");
+                },
+                _ => segments.push("ひゃく"),
+            },
+            Numeral::Exponent(exponent) => {
+                if let Some(last) = segments.last_mut() {
+                    *last = japanese_kana_sokuon(last, exponent, ten_sokuon);
+                }
+
+                segments.push(exponent.to_kana());
+            },
+            Numeral::FractionUnit(unit) => segments.push(unit.to_kana()),
+            Numeral::Point => {
+                after_point = true;
+
+                segments.push("てん");
+            },
+            Numeral::Sign(ChineseSign::正) => segments.push("プラス"),
+            Numeral::Sign(ChineseSign::負) => segments.push("マイナス"),
+        }
+    }
+
+    segments.concat()
 }
@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use super::*;
-use crate::{NumberScript, ScriptStyle};
+use crate::{LocaleRules, NumberScript, ScriptStyle};
 
 #[test]
 fn test_unsigned_integer_to_chinese_low() {
@@ -9,10 +9,9 @@ fn test_unsigned_integer_to_chinese_low() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                unsigned_integer_to_chinese_low(
+                numerals_to_chinese(
                     NumberScript::TraditionalChinese(ScriptStyle::Lower { circle_as_zero: false }),
-                    false,
-                    $value
+                    &unsigned_integer_to_numerals_low(LocaleRules::default(), false, $value)
                 )
             );
         };
@@ -36,10 +35,9 @@ fn test_unsigned_integer_to_chinese_ten_thousand() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                unsigned_integer_to_chinese_ten_thousand(
+                numerals_to_chinese(
                     NumberScript::TraditionalChinese(ScriptStyle::Lower { circle_as_zero: false }),
-                    false,
-                    $value
+                    &unsigned_integer_to_numerals_ten_thousand(LocaleRules::default(), false, $value)
                 )
             );
         };
@@ -64,10 +62,9 @@ fn test_big_unsigned_integer_to_chinese_ten_thousand() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                big_unsigned_integer_to_chinese_ten_thousand(
+                numerals_to_chinese(
                     NumberScript::TraditionalChinese(ScriptStyle::Lower { circle_as_zero: false }),
-                    false,
-                    ($value as u128).into()
+                    &big_unsigned_integer_to_numerals_ten_thousand(LocaleRules::default(), false, ($value as u128).into())
                 )
             );
         };
@@ -92,10 +89,9 @@ fn test_unsigned_integer_to_chinese_middle() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                unsigned_integer_to_chinese_middle(
+                numerals_to_chinese(
                     NumberScript::TraditionalChinese(ScriptStyle::Lower { circle_as_zero: false }),
-                    false,
-                    $value
+                    &unsigned_integer_to_numerals_middle(LocaleRules::default(), false, $value)
                 )
             );
         };
@@ -120,10 +116,9 @@ fn test_big_unsigned_integer_to_chinese_middle() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                big_unsigned_integer_to_chinese_middle(
+                numerals_to_chinese(
                     NumberScript::TraditionalChinese(ScriptStyle::Lower { circle_as_zero: false }),
-                    false,
-                    ($value as u128).into()
+                    &big_unsigned_integer_to_numerals_middle(LocaleRules::default(), false, ($value as u128).into())
                 )
             );
         };
@@ -148,10 +143,9 @@ fn test_unsigned_integer_to_chinese_high() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                unsigned_integer_to_chinese_high(
+                numerals_to_chinese(
                     NumberScript::TraditionalChinese(ScriptStyle::Lower { circle_as_zero: false }),
-                    false,
-                    $value
+                    &unsigned_integer_to_numerals_high(LocaleRules::default(), false, $value)
                 )
             );
         };
@@ -181,10 +175,9 @@ fn test_big_unsigned_integer_to_chinese_high() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                big_unsigned_integer_to_chinese_high(
+                numerals_to_chinese(
                     NumberScript::TraditionalChinese(ScriptStyle::Lower { circle_as_zero: false }),
-                    false,
-                    ($value as u128).into()
+                    &big_unsigned_integer_to_numerals_high(LocaleRules::default(), false, ($value as u128).into())
                 )
             );
         };
@@ -214,10 +207,9 @@ fn test_fraction_compat() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                positive_float_to_chinese(
+                numerals_to_chinese(
                     NumberScript::TraditionalChinese(ScriptStyle::Lower { circle_as_zero: false }),
-                    ChineseCountMethod::TenThousand,
-                    $value
+                    &positive_float_to_numerals(ChineseCountMethod::TenThousand, $value)
                 )
             );
         };
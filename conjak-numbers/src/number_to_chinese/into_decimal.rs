@@ -0,0 +1,69 @@
+use alloc::borrow::Cow;
+
+use crate::{Decimal, FractionFormat, FractionMode, NumberToChineseError, RoundingMode};
+
+/// 能轉成十進位數值的型別，也就是所有基本數值型別與 `&Decimal`。只有本 crate 能為型別實作它。
+pub trait IntoDecimal: Sized {
+    /// 沒有指定小數部分的讀法時使用的格式，與 `to_chinese` 的讀法相同。
+    const DEFAULT_FRACTION: FractionFormat =
+        FractionFormat::new(FractionMode::Currency, 2, RoundingMode::HalfUp);
+
+    /// 轉成十進位數值。所有以它取得數值的方法都遵循同樣的規則：浮點數為 `NaN` 或正無限大時回傳 `Overflow`，為負無限大時回傳 `Underflow`。
+    fn into_decimal<'a>(self) -> Result<Cow<'a, Decimal>, NumberToChineseError>
+    where
+        Self: 'a;
+}
+
+/// 將浮點數轉成十進位數值的結果轉成錯誤。負無限大為 `Underflow`，其他無法轉換的值為 `Overflow`。
+#[inline]
+fn float_to_decimal<'a>(
+    value: Option<Decimal>,
+    negative: bool,
+) -> Result<Cow<'a, Decimal>, NumberToChineseError> {
+    match value {
+        Some(value) => Ok(Cow::Owned(value)),
+        None if negative => Err(NumberToChineseError::Underflow),
+        None => Err(NumberToChineseError::Overflow),
+    }
+}
+
+macro_rules! impl_into_decimal_for_integer {
+    ($($t:ty),* $(,)*) => {
+        $(
+            impl IntoDecimal for $t {
+                #[inline]
+                fn into_decimal<'a>(self) -> Result<Cow<'a, Decimal>, NumberToChineseError> {
+                    Ok(Cow::Owned(Decimal::from(self)))
+                }
+            }
+        )*
+    };
+}
+
+impl_into_decimal_for_integer!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
+
+impl IntoDecimal for f32 {
+    #[inline]
+    fn into_decimal<'a>(self) -> Result<Cow<'a, Decimal>, NumberToChineseError> {
+        float_to_decimal(Decimal::from_f32(self), self < 0.0)
+    }
+}
+
+impl IntoDecimal for f64 {
+    #[inline]
+    fn into_decimal<'a>(self) -> Result<Cow<'a, Decimal>, NumberToChineseError> {
+        float_to_decimal(Decimal::from_f64(self), self < 0.0)
+    }
+}
+
+impl IntoDecimal for &Decimal {
+    const DEFAULT_FRACTION: FractionFormat =
+        FractionFormat::new(FractionMode::DecimalPoint, usize::MAX, RoundingMode::Truncate);
+
+    #[inline]
+    fn into_decimal<'a>(self) -> Result<Cow<'a, Decimal>, NumberToChineseError>
+    where
+        Self: 'a, {
+        Ok(Cow::Borrowed(self))
+    }
+}
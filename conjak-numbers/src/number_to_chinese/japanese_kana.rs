@@ -0,0 +1,88 @@
+use alloc::string::String;
+
+use super::{decimal_to_numerals, functions::numerals_to_japanese_kana, IntoDecimal};
+use crate::{
+    ChineseCountMethod, Decimal, FractionFormat, FractionMode, KanaFormat, KanaScript,
+    LocalePreset, NumberScript, NumberToChineseError, RoundingMode, ScriptStyle,
+};
+
+/// 將平假名轉成片假名，其他字元保持不變。
+fn hiragana_to_katakana(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            'ぁ'..='ゖ' => char::from_u32(c as u32 + 0x60).unwrap(),
+            _ => c,
+        })
+        .collect()
+}
+
+/// 將十進位數值轉成日文的假名讀音，例如 **「さんびゃくろくじゅうご」**、**「いっちょう」**。
+///
+/// 依照日本的寫法（`LocalePreset::Jp`）讀出數字，數值範圍的限制與 `from_decimal_to_chinese` 相同。負數前面會加上 **「マイナス」**，小數部分以 **「てん」** 逐位讀出。
+pub fn from_decimal_to_japanese_kana(
+    method: ChineseCountMethod,
+    value: &Decimal,
+    format: KanaFormat,
+) -> Result<String, NumberToChineseError> {
+    let numerals = decimal_to_numerals(
+        NumberScript::Japanese(ScriptStyle::Lower { circle_as_zero: false }),
+        method,
+        LocalePreset::Jp.rules(),
+        value,
+        FractionFormat::new(FractionMode::DecimalPoint, usize::MAX, RoundingMode::Truncate),
+    )?;
+
+    let mut s = numerals_to_japanese_kana(&numerals, format.ten_sokuon);
+
+    if format.script == KanaScript::Katakana {
+        s = hiragana_to_katakana(s.as_str());
+    }
+
+    Ok(s)
+}
+
+/// 讓整數、浮點數與 `Decimal` 能轉成日文的假名讀音。
+pub trait NumberToJapaneseKana: IntoDecimal {
+    /// 將數值轉成日文的假名讀音，規則請見 `from_decimal_to_japanese_kana`。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use conjak_numbers::{
+    ///     ChineseCountMethod, KanaFormat, KanaScript, NumberToJapaneseKana, TenSokuon,
+    /// };
+    ///
+    /// let method = ChineseCountMethod::TenThousand;
+    ///
+    /// assert_eq!("さんびゃく", 300u16.to_japanese_kana(method, KanaFormat::default()).unwrap());
+    /// assert_eq!(
+    ///     "はっせんはっぴゃく",
+    ///     8800u16.to_japanese_kana(method, KanaFormat::default()).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "いっせんまん",
+    ///     1000_0000u32.to_japanese_kana(method, KanaFormat::default()).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "ジッチョウ",
+    ///     10_0000_0000_0000u64
+    ///         .to_japanese_kana(method, KanaFormat::new(KanaScript::Katakana, TenSokuon::Ji))
+    ///         .unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "いちまんおく",
+    ///     1_0000_0000_0000u64
+    ///         .to_japanese_kana(ChineseCountMethod::Middle, KanaFormat::default())
+    ///         .unwrap()
+    /// );
+    /// ```
+    fn to_japanese_kana(
+        self,
+        method: ChineseCountMethod,
+        format: KanaFormat,
+    ) -> Result<String, NumberToChineseError> {
+        from_decimal_to_japanese_kana(method, &*self.into_decimal()?, format)
+    }
+}
+
+impl<T: IntoDecimal> NumberToJapaneseKana for T {}
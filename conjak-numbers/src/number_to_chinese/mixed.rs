@@ -0,0 +1,80 @@
+use alloc::string::String;
+
+use super::{check_decimal_range, functions::big_unsigned_integer_to_mixed_ten_thousand, IntoDecimal};
+use crate::{
+    ChineseCountMethod, Decimal, DigitWidth, MixedFormat, NumberScript, NumberToChineseError,
+};
+
+/// 將十進位數值轉成阿拉伯數字搭配大單位的寫法，例如 **「1億2345万6789」**、**「12억 3456만 7898」**。
+///
+/// 使用 **「萬進」**，因此數值的絕對值不能大於或等於 `1e52`。小數部分以小數點接在最後一組數字後面。
+pub fn from_decimal_to_mixed(
+    number_script: NumberScript,
+    format: MixedFormat,
+    value: &Decimal,
+) -> Result<String, NumberToChineseError> {
+    check_decimal_range(ChineseCountMethod::TenThousand, value)?;
+
+    let mut s = String::new();
+
+    if value.is_negative() {
+        s.push(match format.width {
+            DigitWidth::Half => '-',
+            DigitWidth::Full => '－',
+        });
+    }
+
+    s.push_str(
+        big_unsigned_integer_to_mixed_ten_thousand(number_script, format, value.integer().clone())
+            .as_str(),
+    );
+
+    if !value.fraction().is_empty() {
+        // 略過的個位數組在小數點前面要補回零，例如「1万0.5」
+        if !s.ends_with(|c: char| c.is_numeric()) {
+            if let NumberScript::Korean(_) = number_script {
+                s.push(' ');
+            }
+
+            s.push(format.width.digit(0));
+        }
+
+        s.push(match format.width {
+            DigitWidth::Half => '.',
+            DigitWidth::Full => '．',
+        });
+
+        for &d in value.fraction() {
+            s.push(format.width.digit(d));
+        }
+    }
+
+    Ok(s)
+}
+
+/// 讓整數、浮點數與 `Decimal` 能轉成阿拉伯數字搭配大單位的寫法。
+pub trait NumberToMixed: IntoDecimal {
+    /// 將數值轉成阿拉伯數字搭配大單位的寫法，規則請見 `from_decimal_to_mixed`。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use conjak_numbers::{DigitWidth, MixedFormat, NumberScript, NumberToMixed, ScriptStyle};
+    ///
+    /// let japanese = NumberScript::Japanese(ScriptStyle::Lower { circle_as_zero: false });
+    /// let korean = NumberScript::Korean(ScriptStyle::Lower { circle_as_zero: false });
+    ///
+    /// assert_eq!("1億2345万6789", 123456789u32.to_mixed(japanese, MixedFormat::default()).unwrap());
+    /// assert_eq!("３億４０００万", 340000000u32.to_mixed(japanese, MixedFormat::new(DigitWidth::Full, true)).unwrap());
+    /// assert_eq!("3억 4000만", 340000000u32.to_mixed(korean, MixedFormat::default()).unwrap());
+    /// ```
+    fn to_mixed(
+        self,
+        number_script: NumberScript,
+        format: MixedFormat,
+    ) -> Result<String, NumberToChineseError> {
+        from_decimal_to_mixed(number_script, format, &*self.into_decimal()?)
+    }
+}
+
+impl<T: IntoDecimal> NumberToMixed for T {}
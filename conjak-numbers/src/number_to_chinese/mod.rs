@@ -1,18 +1,50 @@
+mod compact;
+mod digits;
+mod financial_amount;
 mod functions;
+mod into_decimal;
+mod japanese_kana;
+mod mixed;
 mod naive;
+mod native_korean;
 mod number_to_chinese_error;
+mod ordinal;
+mod quoc_ngu;
+mod ratio;
+mod romanization;
+mod spacing;
+mod suzhou;
 mod traits;
 
 mod functions_test;
 
 use alloc::string::String;
 
+pub use compact::*;
+pub use digits::*;
+pub use financial_amount::*;
 use functions::*;
+use into_decimal::*;
+pub use japanese_kana::*;
+pub use mixed::*;
 pub use naive::*;
+pub use native_korean::*;
 pub use number_to_chinese_error::*;
+pub use ordinal::*;
+pub use quoc_ngu::*;
+pub use ratio::*;
+pub use romanization::*;
+pub use spacing::*;
+pub use suzhou::*;
 pub use traits::*;
 
-use crate::{ChineseCountMethod, ChineseSign, NumberScript};
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use crate::{
+    ChineseCountMethod, ChineseSign, Decimal, FractionFormat, FractionMode, LocaleRules,
+    NumberScript, RoundingMode,
+};
 
 // TODO unsigned integer
 
@@ -116,7 +148,10 @@ pub fn from_u128_to_chinese_low(
         return Err(NumberToChineseError::Overflow);
     }
 
-    Ok(unsigned_integer_to_chinese_low(number_script, false, value))
+    Ok(numerals_to_chinese(
+        number_script,
+        &unsigned_integer_to_numerals_low(LocaleRules::default(), false, value),
+    ))
 }
 
 /// 將 `u128` 整數轉成中文數字，使用 **「萬進」**。
@@ -125,7 +160,10 @@ pub fn from_u128_to_chinese_ten_thousand(
     number_script: NumberScript,
     value: u128,
 ) -> String {
-    unsigned_integer_to_chinese_ten_thousand(number_script, false, value)
+    numerals_to_chinese(
+        number_script,
+        &unsigned_integer_to_numerals_ten_thousand(LocaleRules::default(), false, value),
+    )
 }
 
 /// 將 `u128` 整數轉成中文數字，使用 **「中數」**。
@@ -134,7 +172,10 @@ pub fn from_u128_to_chinese_middle(
     number_script: NumberScript,
     value: u128,
 ) -> String {
-    unsigned_integer_to_chinese_middle(number_script, false, value)
+    numerals_to_chinese(
+        number_script,
+        &unsigned_integer_to_numerals_middle(LocaleRules::default(), false, value),
+    )
 }
 
 /// 將 `u128` 整數轉成中文數字，使用 **「上數」**。
@@ -143,7 +184,10 @@ pub fn from_u128_to_chinese_high(
     number_script: NumberScript,
     value: u128,
 ) -> String {
-    unsigned_integer_to_chinese_high(number_script, false, value)
+    numerals_to_chinese(
+        number_script,
+        &unsigned_integer_to_numerals_high(LocaleRules::default(), false, value),
+    )
 }
 
 /// 將 `usize` 整數轉成中文數字，使用 **「下數」**。數值不能大於或等於 `1_0000_0000_0000_0000`。
@@ -426,20 +470,46 @@ pub fn from_f32_to_chinese_high(
     from_f64_to_chinese_high(number_script, value as f64)
 }
 
+/// 依照 `to_chinese` 的讀法把浮點數組成一串 `Numeral`，小數部分讀作 **「角」**、**「分」**。
+pub(crate) fn f64_to_numerals(method: ChineseCountMethod, value: f64) -> Vec<Numeral> {
+    if value < 0.0 {
+        let mut numerals = positive_float_to_numerals(method, -value);
+
+        numerals.insert(0, Numeral::Sign(ChineseSign::負));
+
+        numerals
+    } else {
+        positive_float_to_numerals(method, value)
+    }
+}
+
 #[inline]
 fn from_f64_to_chinese(
     number_script: NumberScript,
     method: ChineseCountMethod,
     value: f64,
 ) -> String {
-    if value < 0.0 {
-        let mut s = positive_float_to_chinese(number_script, method, -value);
-
-        s.insert_str(0, ChineseSign::負.to_str(number_script));
+    numerals_to_chinese(number_script, &f64_to_numerals(method, value))
+}
 
-        s
+/// 檢查浮點數是否在算術類型能表示的範圍內。
+pub(crate) fn check_f64_range(
+    method: ChineseCountMethod,
+    value: f64,
+) -> Result<(), NumberToChineseError> {
+    let limit = match method {
+        ChineseCountMethod::Low => 1e16,
+        ChineseCountMethod::TenThousand => 1e52,
+        ChineseCountMethod::Middle => 1e96,
+        ChineseCountMethod::High => return Ok(()),
+    };
+
+    if value >= limit {
+        Err(NumberToChineseError::Overflow)
+    } else if value <= -limit {
+        Err(NumberToChineseError::Underflow)
     } else {
-        positive_float_to_chinese(number_script, method, value)
+        Ok(())
     }
 }
 
@@ -449,11 +519,7 @@ pub fn from_f64_to_chinese_low(
     number_script: NumberScript,
     value: f64,
 ) -> Result<String, NumberToChineseError> {
-    if value >= 1_0000_0000_0000_0000f64 {
-        return Err(NumberToChineseError::Overflow);
-    } else if value <= -1_0000_0000_0000_0000f64 {
-        return Err(NumberToChineseError::Underflow);
-    }
+    check_f64_range(ChineseCountMethod::Low, value)?;
 
     Ok(from_f64_to_chinese(number_script, ChineseCountMethod::Low, value))
 }
@@ -464,11 +530,7 @@ pub fn from_f64_to_chinese_ten_thousand(
     number_script: NumberScript,
     value: f64,
 ) -> Result<String, NumberToChineseError> {
-    if value >= 1e52 {
-        return Err(NumberToChineseError::Overflow);
-    } else if value <= -1e52 {
-        return Err(NumberToChineseError::Underflow);
-    }
+    check_f64_range(ChineseCountMethod::TenThousand, value)?;
 
     Ok(from_f64_to_chinese(number_script, ChineseCountMethod::TenThousand, value))
 }
@@ -479,11 +541,7 @@ pub fn from_f64_to_chinese_middle(
     number_script: NumberScript,
     value: f64,
 ) -> Result<String, NumberToChineseError> {
-    if value >= 1e96 {
-        return Err(NumberToChineseError::Overflow);
-    } else if value <= -1e96 {
-        return Err(NumberToChineseError::Underflow);
-    }
+    check_f64_range(ChineseCountMethod::Middle, value)?;
 
     Ok(from_f64_to_chinese(number_script, ChineseCountMethod::Middle, value))
 }
@@ -496,3 +554,116 @@ pub fn from_f64_to_chinese_high(
 ) -> String {
     from_f64_to_chinese(number_script, ChineseCountMethod::High, value)
 }
+
+// TODO decimal
+
+/// 檢查十進位數值的整數部分是否在算術類型能表示的範圍內。
+pub(crate) fn check_decimal_range(
+    method: ChineseCountMethod,
+    value: &Decimal,
+) -> Result<(), NumberToChineseError> {
+    let limit = match method {
+        ChineseCountMethod::Low => Some(16),
+        ChineseCountMethod::TenThousand => Some(52),
+        ChineseCountMethod::Middle => Some(96),
+        ChineseCountMethod::High => None,
+    };
+
+    if limit.is_some_and(|limit| *value.integer() >= BigUint::from(10u8).pow(limit)) {
+        return Err(if value.is_negative() {
+            NumberToChineseError::Underflow
+        } else {
+            NumberToChineseError::Overflow
+        });
+    }
+
+    Ok(())
+}
+
+/// 將任意精度的十進位數值轉成中文數字，小數部分以小數點逐位讀出，不會捨去任何位數。
+///
+/// * 如果使用 **「下數」**，則數值的絕對值不能大於或等於 `1_0000_0000_0000_0000`。
+/// * 如果使用 **「萬進」**，則數值的絕對值不能大於或等於 `1e52`。
+/// * 如果使用 **「中數」**，則數值的絕對值不能大於或等於 `1e96`。
+#[inline]
+pub fn from_decimal_to_chinese(
+    number_script: NumberScript,
+    method: ChineseCountMethod,
+    value: &Decimal,
+) -> Result<String, NumberToChineseError> {
+    from_decimal_to_chinese_with_fraction(
+        number_script,
+        method,
+        value,
+        FractionFormat::new(FractionMode::DecimalPoint, usize::MAX, RoundingMode::Truncate),
+    )
+}
+
+/// 將任意精度的十進位數值轉成中文數字，小數部分依 `fraction` 指定的讀法、位數與捨入方式輸出。數值範圍的限制與 `from_decimal_to_chinese` 相同，並以捨入後的數值判斷。
+#[inline]
+pub fn from_decimal_to_chinese_with_fraction(
+    number_script: NumberScript,
+    method: ChineseCountMethod,
+    value: &Decimal,
+    fraction: FractionFormat,
+) -> Result<String, NumberToChineseError> {
+    from_decimal_to_chinese_with_rules(number_script, method, LocaleRules::default(), value, fraction)
+}
+
+/// 將任意精度的十進位數值轉成中文數字，**「一」** 的省略與 **「兩」** 的使用依照 `rules`，小數部分依 `fraction` 輸出。數值範圍的限制與 `from_decimal_to_chinese_with_fraction` 相同。
+pub fn from_decimal_to_chinese_with_rules(
+    number_script: NumberScript,
+    method: ChineseCountMethod,
+    rules: LocaleRules,
+    value: &Decimal,
+    fraction: FractionFormat,
+) -> Result<String, NumberToChineseError> {
+    Ok(numerals_to_chinese(
+        number_script,
+        &decimal_to_numerals(number_script, method, rules, value, fraction)?,
+    ))
+}
+
+/// 依照 `rules` 與 `fraction` 把十進位數值組成一串 `Numeral`。書寫方式只影響日文貨幣單位 **「毛」** 的位置。
+pub(crate) fn decimal_to_numerals(
+    number_script: NumberScript,
+    method: ChineseCountMethod,
+    rules: LocaleRules,
+    value: &Decimal,
+    fraction: FractionFormat,
+) -> Result<Vec<Numeral>, NumberToChineseError> {
+    let value = value.round(fraction.effective_precision(), fraction.rounding);
+
+    check_decimal_range(method, &value)?;
+
+    let mut numerals = match fraction.mode {
+        FractionMode::DecimalPoint => {
+            let mut numerals =
+                big_unsigned_integer_to_numerals_by_method(rules, method, value.integer().clone());
+
+            if !value.fraction().is_empty() {
+                numerals.extend(fraction_digits_to_numerals(value.fraction()));
+            }
+
+            numerals
+        },
+        FractionMode::Currency | FractionMode::SmallUnit => {
+            // 只有小數時不讀出整數的「零」
+            let mut numerals = if value.integer().is_zero() && !value.fraction().is_empty() {
+                Vec::new()
+            } else {
+                big_unsigned_integer_to_numerals_by_method(rules, method, value.integer().clone())
+            };
+
+            numerals.extend(fraction_units_to_numerals(number_script, fraction.mode, value.fraction()));
+
+            numerals
+        },
+    };
+
+    if value.is_negative() {
+        numerals.insert(0, Numeral::Sign(ChineseSign::負));
+    }
+
+    Ok(numerals)
+}
@@ -8,9 +8,10 @@ use num_bigint::BigUint;
 use num_traits::float::FloatCore;
 use num_traits::{FromPrimitive, ToPrimitive, Zero};
 
+use super::{functions::fraction_digits_to_chinese, IntoDecimal};
 use crate::{
     chinese_characters::{ChineseNumber, ChinesePoint, ChineseSign},
-    NumberScript,
+    Decimal, NumberScript, NumberToChineseError,
 };
 
 fn unsigned_integer_to_chinese(
@@ -240,3 +241,95 @@ pub fn from_f64_to_chinese_naive(
 ) -> String {
     from_f64_to_chinese(number_script, value)
 }
+
+/// 將任意精度的十進位數值轉成中文數字，不進行單位計算，也不會捨去任何位數。
+#[inline]
+pub fn from_decimal_to_chinese_naive(
+    number_script: NumberScript,
+    value: &Decimal,
+) -> String {
+    let mut s = big_unsigned_integer_to_chinese(number_script, value.integer().clone());
+
+    if !value.fraction().is_empty() {
+        s.push_str(fraction_digits_to_chinese(number_script, value.fraction()).as_str());
+    }
+
+    if value.is_negative() {
+        s.insert_str(0, ChineseSign::負.to_str(number_script));
+    }
+
+    s
+}
+
+/// 算籌的縱式數字（個位、百位……使用），依序為一到九。
+const COUNTING_ROD_UNIT_DIGIT_ONE: u32 = 0x1D360;
+/// 算籌的橫式數字（十位、千位……使用），依序為一到九。
+const COUNTING_ROD_TENS_DIGIT_ONE: u32 = 0x1D369;
+/// 算籌中空位的寫法。
+const COUNTING_ROD_ZERO: char = '〇';
+
+#[inline]
+fn counting_rod_digit(d: u8, place: isize) -> char {
+    if d == 0 {
+        return COUNTING_ROD_ZERO;
+    }
+
+    // 「縱橫相間」：個位用縱式，十位用橫式，以此類推
+    let one =
+        if place % 2 == 0 { COUNTING_ROD_UNIT_DIGIT_ONE } else { COUNTING_ROD_TENS_DIGIT_ONE };
+
+    char::from_u32(one + d as u32 - 1).unwrap()
+}
+
+/// 將任意精度的十進位數值轉成算籌數字，例如 123 會轉成 **「𝍠𝍪𝍢」**。
+///
+/// 每一位數依照位值輪流使用縱式與橫式，個位為縱式、十位為橫式，小數的第一位為橫式、第二位為縱式，以此類推。空位寫成 **「〇」**。負號與小數點依照書寫方式使用 **「負」**、**「點」** 等字。
+pub fn from_decimal_to_counting_rods(
+    number_script: NumberScript,
+    value: &Decimal,
+) -> String {
+    let mut s = String::new();
+
+    if value.is_negative() {
+        s.push_str(ChineseSign::負.to_str(number_script));
+    }
+
+    let integer = value.integer().to_string();
+    let length = integer.len() as isize;
+
+    for (i, b) in integer.bytes().enumerate() {
+        s.push(counting_rod_digit(b - b'0', length - 1 - i as isize));
+    }
+
+    if !value.fraction().is_empty() {
+        s.push_str(ChinesePoint::to_str(number_script));
+
+        for (i, &d) in value.fraction().iter().enumerate() {
+            s.push(counting_rod_digit(d, -(i as isize) - 1));
+        }
+    }
+
+    s
+}
+
+/// 讓整數、浮點數與 `Decimal` 能轉成算籌數字。
+pub trait NumberToCountingRods: IntoDecimal {
+    /// 將數值轉成算籌數字，規則請見 `from_decimal_to_counting_rods`。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use conjak_numbers::{NumberScript, NumberToCountingRods, ScriptStyle};
+    ///
+    /// let script = NumberScript::TraditionalChinese(ScriptStyle::Lower { circle_as_zero: false });
+    ///
+    /// assert_eq!("𝍠𝍪𝍢", 123u8.to_counting_rods(script).unwrap());
+    /// assert_eq!("𝍥〇𝍡", 602u16.to_counting_rods(script).unwrap());
+    /// assert_eq!("負𝍡點𝍭", (-2.5f64).to_counting_rods(script).unwrap());
+    /// ```
+    fn to_counting_rods(self, number_script: NumberScript) -> Result<String, NumberToChineseError> {
+        Ok(from_decimal_to_counting_rods(number_script, &*self.into_decimal()?))
+    }
+}
+
+impl<T: IntoDecimal> NumberToCountingRods for T {}
@@ -0,0 +1,124 @@
+use alloc::string::String;
+
+use serde::Deserialize;
+
+use crate::NumberToChineseError;
+
+/// 韓文固有語數詞的形式。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NativeKoreanForm {
+    /// 單獨使用的數詞，例如 **「하나」**、**「스물」**、**「아흔아홉」**。
+    #[default]
+    Cardinal,
+    /// 接在量詞前面的冠形詞，例如 **「한」**、**「스무」**、**「스물두」**。
+    Attributive,
+}
+
+const TENS: [&str; 10] = ["", "열", "스물", "서른", "마흔", "쉰", "예순", "일흔", "여든", "아흔"];
+
+const ONES_CARDINAL: [&str; 10] =
+    ["", "하나", "둘", "셋", "넷", "다섯", "여섯", "일곱", "여덟", "아홉"];
+
+const ONES_ATTRIBUTIVE: [&str; 10] =
+    ["", "한", "두", "세", "네", "다섯", "여섯", "일곱", "여덟", "아홉"];
+
+/// 單獨使用時的序數個位數，「一」寫成 **「첫」**。
+const ONES_ORDINAL_ALONE: [&str; 10] =
+    ["", "첫", "둘", "셋", "넷", "다섯", "여섯", "일곱", "여덟", "아홉"];
+
+/// 接在十位數後面時的序數個位數，例如 **「열한째」**、**「스물두째」**。
+const ONES_ORDINAL: [&str; 10] = ["", "한", "두", "셋", "넷", "다섯", "여섯", "일곱", "여덟", "아홉"];
+
+#[inline]
+fn check_range(value: u8) -> Result<(), NumberToChineseError> {
+    if (1..100).contains(&value) {
+        Ok(())
+    } else {
+        Err(NumberToChineseError::NativeKoreanOutOfRange)
+    }
+}
+
+fn compose(value: u8, ones: &[&str; 10], ones_alone: &[&str; 10], twenty: &str) -> String {
+    let tens = (value / 10) as usize;
+    let d = (value % 10) as usize;
+
+    let mut s = String::new();
+
+    match (tens, d) {
+        (2, 0) => s.push_str(twenty),
+        (0, _) => s.push_str(ones_alone[d]),
+        _ => {
+            s.push_str(TENS[tens]);
+            s.push_str(ones[d]);
+        },
+    }
+
+    s
+}
+
+/// 將 1 到 99 的整數轉成韓文固有語數詞。
+pub fn from_u8_to_native_korean(
+    value: u8,
+    form: NativeKoreanForm,
+) -> Result<String, NumberToChineseError> {
+    check_range(value)?;
+
+    Ok(match form {
+        NativeKoreanForm::Cardinal => compose(value, &ONES_CARDINAL, &ONES_CARDINAL, "스물"),
+        // 「스물」在量詞前面會脫落成「스무」
+        NativeKoreanForm::Attributive => {
+            compose(value, &ONES_ATTRIBUTIVE, &ONES_ATTRIBUTIVE, "스무")
+        },
+    })
+}
+
+/// 將 1 到 99 的整數轉成韓文固有語的序數，例如 **「첫째」**、**「스무째」**。
+pub(crate) fn from_u8_to_native_korean_ordinal(value: u8) -> Result<String, NumberToChineseError> {
+    check_range(value)?;
+
+    let mut s = compose(value, &ONES_ORDINAL, &ONES_ORDINAL_ALONE, "스무");
+
+    s.push('째');
+
+    Ok(s)
+}
+
+/// 讓整數能轉成韓文固有語數詞。
+pub trait NumberToNativeKorean {
+    /// 將整數轉成韓文固有語數詞。固有語數詞只有 1 到 99，超出範圍時回傳 `NativeKoreanOutOfRange`。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use conjak_numbers::{NativeKoreanForm, NumberToNativeKorean};
+    ///
+    /// assert_eq!("스물다섯", 25u8.to_native_korean(NativeKoreanForm::Cardinal).unwrap());
+    /// assert_eq!("아흔아홉", 99i32.to_native_korean(NativeKoreanForm::Cardinal).unwrap());
+    /// assert_eq!("한", 1u8.to_native_korean(NativeKoreanForm::Attributive).unwrap());
+    /// assert_eq!("스무", 20u8.to_native_korean(NativeKoreanForm::Attributive).unwrap());
+    /// assert!(100u8.to_native_korean(NativeKoreanForm::Cardinal).is_err());
+    /// ```
+    fn to_native_korean(self, form: NativeKoreanForm) -> Result<String, NumberToChineseError>;
+}
+
+macro_rules! impl_number_to_native_korean {
+    ($($t:ty),* $(,)*) => {
+        $(
+            impl NumberToNativeKorean for $t {
+                #[inline]
+                fn to_native_korean(
+                    self,
+                    form: NativeKoreanForm,
+                ) -> Result<String, NumberToChineseError> {
+                    match u8::try_from(self) {
+                        Ok(value) => from_u8_to_native_korean(value, form),
+                        Err(_) => Err(NumberToChineseError::NativeKoreanOutOfRange),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_number_to_native_korean!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
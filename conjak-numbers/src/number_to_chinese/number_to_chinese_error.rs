@@ -3,9 +3,16 @@ use std::error::Error;
 
 /// 將數值轉成中文數字時發生的錯誤。
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum NumberToChineseError {
     Overflow,
     Underflow,
+    /// 這種書寫方式不支援所要求的格式。
+    UnsupportedNumberScript,
+    /// 韓文固有語數詞只能表示 1 到 99。
+    NativeKoreanOutOfRange,
+    /// 分數的分母為零。
+    ZeroDenominator,
 }
 
 impl Display for NumberToChineseError {
@@ -14,6 +21,13 @@ impl Display for NumberToChineseError {
         match self {
             NumberToChineseError::Overflow => f.write_str("number is too large"),
             NumberToChineseError::Underflow => f.write_str("number is too small"),
+            NumberToChineseError::UnsupportedNumberScript => {
+                f.write_str("number script is not supported for this format")
+            },
+            NumberToChineseError::NativeKoreanOutOfRange => {
+                f.write_str("native korean numerals only cover 1 to 99")
+            },
+            NumberToChineseError::ZeroDenominator => f.write_str("denominator is zero"),
         }
     }
 }
@@ -0,0 +1,117 @@
+use alloc::string::String;
+
+use serde::Deserialize;
+
+use super::{from_decimal_to_chinese, from_u8_to_native_korean_ordinal};
+use crate::{ChineseCountMethod, Decimal, NumberScript, NumberToChineseError, ScriptStyle};
+
+/// 序數的寫法。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrdinalStyle {
+    /// 在數字前面加上 **「第」**，韓文為 **「제」**（大寫仍為 **「第」**），例如 **「第一」**、**「제삼」**。
+    #[default]
+    Prefix,
+    /// 日文在數字後面加上 **「番目」**，例如 **「三番目」**。
+    Banme,
+    /// 日文在數字後面加上 **「番」**，例如 **「三番」**。
+    Ban,
+    /// 韓文固有語的序數，例如 **「첫째」**、**「둘째」**、**「스무째」**，只能表示 1 到 99。
+    NativeKorean,
+}
+
+fn integer_to_ordinal(
+    number_script: NumberScript,
+    method: ChineseCountMethod,
+    style: OrdinalStyle,
+    value: &Decimal,
+) -> Result<String, NumberToChineseError> {
+    debug_assert!(value.fraction().is_empty());
+
+    if value.is_negative() {
+        return Err(NumberToChineseError::Underflow);
+    }
+
+    match (style, number_script) {
+        (OrdinalStyle::Prefix, _) => {
+            let prefix = match number_script {
+                NumberScript::Korean(ScriptStyle::Lower { .. }) => "제",
+                _ => "第",
+            };
+
+            let mut s = String::from(prefix);
+
+            s.push_str(from_decimal_to_chinese(number_script, method, value)?.as_str());
+
+            Ok(s)
+        },
+        (OrdinalStyle::Banme | OrdinalStyle::Ban, NumberScript::Japanese(_)) => {
+            let mut s = from_decimal_to_chinese(number_script, method, value)?;
+
+            s.push_str(if style == OrdinalStyle::Banme { "番目" } else { "番" });
+
+            Ok(s)
+        },
+        (OrdinalStyle::NativeKorean, NumberScript::Korean(_)) => {
+            match u8::try_from(value.integer()) {
+                Ok(v) => from_u8_to_native_korean_ordinal(v),
+                Err(_) => Err(NumberToChineseError::NativeKoreanOutOfRange),
+            }
+        },
+        _ => Err(NumberToChineseError::UnsupportedNumberScript),
+    }
+}
+
+/// 讓整數能轉成序數。
+pub trait NumberToOrdinal {
+    /// 將整數轉成序數。
+    ///
+    /// * `Prefix` 可用於所有書寫方式。
+    /// * `Banme`、`Ban` 只能用於日文。
+    /// * `NativeKorean` 只能用於韓文，且數值必須介於 1 到 99 之間，否則回傳 `NativeKoreanOutOfRange`。
+    ///
+    /// 負數一律回傳 `Underflow`。其他數值範圍的限制與 `to_chinese` 相同。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use conjak_numbers::{
+    ///     ChineseCountMethod, NumberScript, NumberToOrdinal, OrdinalStyle, ScriptStyle,
+    /// };
+    ///
+    /// let japanese = NumberScript::Japanese(ScriptStyle::Lower { circle_as_zero: false });
+    /// let korean = NumberScript::Korean(ScriptStyle::Lower { circle_as_zero: false });
+    ///
+    /// assert_eq!("第十二", 12u8.to_ordinal(japanese, ChineseCountMethod::TenThousand, OrdinalStyle::Prefix).unwrap());
+    /// assert_eq!("三番目", 3u8.to_ordinal(japanese, ChineseCountMethod::TenThousand, OrdinalStyle::Banme).unwrap());
+    /// assert_eq!("제삼", 3u8.to_ordinal(korean, ChineseCountMethod::TenThousand, OrdinalStyle::Prefix).unwrap());
+    /// assert_eq!("첫째", 1u8.to_ordinal(korean, ChineseCountMethod::TenThousand, OrdinalStyle::NativeKorean).unwrap());
+    /// assert_eq!("스물한째", 21u8.to_ordinal(korean, ChineseCountMethod::TenThousand, OrdinalStyle::NativeKorean).unwrap());
+    /// ```
+    fn to_ordinal(
+        self,
+        number_script: NumberScript,
+        method: ChineseCountMethod,
+        style: OrdinalStyle,
+    ) -> Result<String, NumberToChineseError>;
+}
+
+macro_rules! impl_number_to_ordinal {
+    ($($t:ty),* $(,)*) => {
+        $(
+            impl NumberToOrdinal for $t {
+                #[inline]
+                fn to_ordinal(
+                    self,
+                    number_script: NumberScript,
+                    method: ChineseCountMethod,
+                    style: OrdinalStyle,
+                ) -> Result<String, NumberToChineseError> {
+                    integer_to_ordinal(number_script, method, style, &Decimal::from(self))
+                }
+            }
+        )*
+    };
+}
+
+impl_number_to_ordinal!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
@@ -0,0 +1,197 @@
+use alloc::{string::String, vec::Vec};
+
+use num_bigint::BigUint;
+use num_traits::{ToPrimitive, Zero};
+use serde::Deserialize;
+
+use super::IntoDecimal;
+use crate::{Decimal, NumberToChineseError};
+
+/// 越南語國語字的地區用語。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VietnameseDialect {
+    /// 北部用語，千為 **「nghìn」**，十位的零讀作 **「linh」**，例如 **「một trăm linh năm」**。
+    #[default]
+    Northern,
+    /// 南部用語，千為 **「ngàn」**，十位的零讀作 **「lẻ」**，例如 **「một trăm lẻ năm」**。
+    Southern,
+}
+
+impl VietnameseDialect {
+    #[inline]
+    const fn thousand(self) -> &'static str {
+        match self {
+            Self::Northern => "nghìn",
+            Self::Southern => "ngàn",
+        }
+    }
+
+    #[inline]
+    const fn zero_tens(self) -> &'static str {
+        match self {
+            Self::Northern => "linh",
+            Self::Southern => "lẻ",
+        }
+    }
+}
+
+const DIGITS: [&str; 10] = ["không", "một", "hai", "ba", "bốn", "năm", "sáu", "bảy", "tám", "chín"];
+
+const HUNDRED: &str = "trăm";
+const MILLION: &str = "triệu";
+const BILLION: &str = "tỷ";
+
+/// 讀出一組三位數。`full` 表示前面還有其他數字，此時百位的零也要讀出，例如 **「không trăm linh năm」**。
+fn group_to_words(dialect: VietnameseDialect, value: u16, full: bool, words: &mut Vec<&'static str>) {
+    debug_assert!(value < 1000);
+
+    let hundreds = (value / 100) as usize;
+    let tens = (value / 10 % 10) as usize;
+    let units = (value % 10) as usize;
+
+    if full || hundreds > 0 {
+        words.push(DIGITS[hundreds]);
+        words.push(HUNDRED);
+    }
+
+    match tens {
+        0 => {
+            if units > 0 {
+                if full || hundreds > 0 {
+                    words.push(dialect.zero_tens());
+                }
+
+                words.push(DIGITS[units]);
+            }
+        },
+        1 => {
+            words.push("mười");
+
+            match units {
+                0 => (),
+                5 => words.push("lăm"),
+                _ => words.push(DIGITS[units]),
+            }
+        },
+        _ => {
+            words.push(DIGITS[tens]);
+            words.push("mươi");
+
+            match units {
+                0 => (),
+                1 => words.push("mốt"),
+                4 => words.push("tư"),
+                5 => words.push("lăm"),
+                _ => words.push(DIGITS[units]),
+            }
+        },
+    }
+}
+
+/// 讀出小於十億的數值，依序為 **「triệu」**、**「nghìn」** 與個位的三位數組，全為零的數組會略過。
+fn below_billion_to_words(
+    dialect: VietnameseDialect,
+    value: u32,
+    mut full: bool,
+    words: &mut Vec<&'static str>,
+) {
+    debug_assert!(value < 1_000_000_000);
+
+    for (group, unit) in [
+        ((value / 1_000_000) as u16, Some(MILLION)),
+        ((value / 1000 % 1000) as u16, Some(dialect.thousand())),
+        ((value % 1000) as u16, None),
+    ] {
+        if group == 0 {
+            continue;
+        }
+
+        group_to_words(dialect, group, full, words);
+
+        if let Some(unit) = unit {
+            words.push(unit);
+        }
+
+        full = true;
+    }
+}
+
+/// 以 **「tỷ」** 為單位遞迴讀出整數，例如 **「một nghìn tỷ」**、**「một tỷ tỷ」**。
+fn big_unsigned_integer_to_words(
+    dialect: VietnameseDialect,
+    value: &BigUint,
+    full: bool,
+    words: &mut Vec<&'static str>,
+) {
+    let big_billion = BigUint::from(1_000_000_000u32);
+
+    let high = value / &big_billion;
+    let low = (value % &big_billion).to_u32().unwrap();
+
+    let full = if high.is_zero() {
+        full
+    } else {
+        big_unsigned_integer_to_words(dialect, &high, full, words);
+
+        words.push(BILLION);
+
+        true
+    };
+
+    if low > 0 {
+        below_billion_to_words(dialect, low, full, words);
+    }
+}
+
+/// 將十進位數值轉成越南語國語字的讀法，例如 **「một trăm hai mươi ba」**。
+///
+/// * 十位是零而個位不是零時，讀作 **「linh」**（北部）或 **「lẻ」**（南部），例如 **「một trăm linh năm」**。
+/// * 十位是二以上時，個位的一讀作 **「mốt」**、四讀作 **「tư」**，例如 **「hai mươi mốt」**、**「hai mươi tư」**。
+/// * 十位不是零時，個位的五讀作 **「lăm」**，例如 **「mười lăm」**、**「ba mươi lăm」**。
+/// * 每三位一組，單位為 **「nghìn」**（**「ngàn」**）、**「triệu」**、**「tỷ」**，更大的數值再組合，例如 **「một nghìn tỷ」**。前面已經有數字時，後面的數組會完整讀出，例如 **「một nghìn không trăm linh năm」**。
+/// * 負數前面加上 **「âm」**，小數部分在 **「phẩy」** 後面逐位讀出。
+pub fn from_decimal_to_quoc_ngu(dialect: VietnameseDialect, value: &Decimal) -> String {
+    let mut words: Vec<&'static str> = Vec::new();
+
+    if value.is_negative() {
+        words.push("âm");
+    }
+
+    if value.integer().is_zero() {
+        words.push(DIGITS[0]);
+    } else {
+        big_unsigned_integer_to_words(dialect, value.integer(), false, &mut words);
+    }
+
+    if !value.fraction().is_empty() {
+        words.push("phẩy");
+
+        for &d in value.fraction() {
+            words.push(DIGITS[d as usize]);
+        }
+    }
+
+    words.join(" ")
+}
+
+/// 讓整數、浮點數與 `Decimal` 能轉成越南語國語字的讀法。
+pub trait NumberToQuocNgu: IntoDecimal {
+    /// 將數值轉成越南語國語字的讀法，規則請見 `from_decimal_to_quoc_ngu`。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use conjak_numbers::{NumberToQuocNgu, VietnameseDialect};
+    ///
+    /// assert_eq!("một trăm hai mươi ba", 123u8.to_quoc_ngu(VietnameseDialect::Northern).unwrap());
+    /// assert_eq!("một trăm lẻ năm", 105u8.to_quoc_ngu(VietnameseDialect::Southern).unwrap());
+    /// assert_eq!("hai mươi mốt", 21u8.to_quoc_ngu(VietnameseDialect::Northern).unwrap());
+    /// assert_eq!("mười lăm", 15u8.to_quoc_ngu(VietnameseDialect::Northern).unwrap());
+    /// ```
+    fn to_quoc_ngu(self, dialect: VietnameseDialect) -> Result<String, NumberToChineseError> {
+        Ok(from_decimal_to_quoc_ngu(dialect, &*self.into_decimal()?))
+    }
+}
+
+impl<T: IntoDecimal> NumberToQuocNgu for T {}
@@ -0,0 +1,244 @@
+use alloc::string::{String, ToString};
+
+use num_traits::Zero;
+use serde::Deserialize;
+
+use super::{
+    check_decimal_range, from_decimal_to_chinese,
+    functions::{big_unsigned_integer_to_chinese_by_method, fraction_units_to_chinese},
+    IntoDecimal,
+};
+use crate::{
+    chinese_characters::{ChineseExponent, ChineseFractionUnit, ChineseNumber, ChineseSign},
+    ChineseCountMethod, Decimal, DigitWidth, FractionMode, LocaleRules, NumberScript,
+    NumberToChineseError, RoundingMode,
+};
+
+/// 比例的讀法。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RatioStyle {
+    /// 百分比，例如 **「百分之二十五點五」**、**「백분의 이십오점오」**。
+    #[default]
+    Percent,
+    /// 千分比，例如 **「千分之三」**。
+    Permille,
+    /// 日文的割分厘，例如 **「二割五分五厘」**。只能用於日文。
+    Wari,
+}
+
+/// 分母與分子之間的連接詞，例如 **「分之」**、**「分の」**、**「분의 」**。
+#[inline]
+const fn fraction_connector(number_script: NumberScript) -> &'static str {
+    match number_script {
+        NumberScript::SimplifiedChinese(_)
+        | NumberScript::TraditionalChinese(_)
+        | NumberScript::Vietnamese(_) => "分之",
+        NumberScript::Japanese(_) => "分の",
+        NumberScript::Korean(_) => "분의 ",
+    }
+}
+
+/// 把分母與分子組成分數，負號放在最前面。
+fn compose_fraction(
+    number_script: NumberScript,
+    negative: bool,
+    denominator: &str,
+    numerator: &str,
+) -> String {
+    let mut s = String::new();
+
+    if negative {
+        s.push_str(ChineseSign::負.to_str(number_script));
+    }
+
+    s.push_str(denominator);
+    s.push_str(fraction_connector(number_script));
+    s.push_str(numerator);
+
+    s
+}
+
+/// 將比例轉成中文的百分比、千分比或割分厘。`value` 是比例本身，例如 `0.255` 代表 **「百分之二十五點五」**。
+///
+/// 百分比與千分比的小數部分以小數點逐位讀出。割分厘最多讀到十二位小數單位，多餘的位數四捨五入；不滿一割時從 **「分」** 開始讀，零寫成 **「零分」**。數值範圍的限制與 `from_decimal_to_chinese` 相同。
+pub fn from_decimal_to_ratio(
+    number_script: NumberScript,
+    method: ChineseCountMethod,
+    style: RatioStyle,
+    value: &Decimal,
+) -> Result<String, NumberToChineseError> {
+    match style {
+        RatioStyle::Percent | RatioStyle::Permille => {
+            let (denominator, n) = match style {
+                RatioStyle::Percent => (ChineseExponent::百, 2),
+                _ => (ChineseExponent::千, 3),
+            };
+
+            let shifted = value.shift_point(n);
+
+            let numerator = from_decimal_to_chinese(
+                number_script,
+                method,
+                &Decimal::new(false, shifted.integer().clone(), shifted.fraction().to_vec()),
+            )?;
+
+            Ok(compose_fraction(
+                number_script,
+                shifted.is_negative(),
+                denominator.to_str(number_script),
+                numerator.as_str(),
+            ))
+        },
+        RatioStyle::Wari => {
+            if !matches!(number_script, NumberScript::Japanese(_)) {
+                return Err(NumberToChineseError::UnsupportedNumberScript);
+            }
+
+            let value = value
+                .shift_point(1)
+                .round(FractionMode::SmallUnit.max_precision(), RoundingMode::HalfUp);
+
+            check_decimal_range(method, &value)?;
+
+            let mut s = String::new();
+
+            if value.is_negative() {
+                s.push_str(ChineseSign::負.to_str(number_script));
+            }
+
+            // 不滿一割時直接從「分」開始讀
+            if !value.integer().is_zero() {
+                s.push_str(
+                    big_unsigned_integer_to_chinese_by_method(
+                        number_script,
+                        LocaleRules::default(),
+                        method,
+                        value.integer().clone(),
+                    )
+                    .as_str(),
+                );
+                s.push('割');
+            }
+
+            if value.fraction().is_empty() {
+                if value.integer().is_zero() {
+                    s.push_str(ChineseNumber::零.to_str(number_script));
+                    s.push_str(ChineseFractionUnit::分.to_str(number_script));
+                }
+            } else {
+                s.push_str(
+                    fraction_units_to_chinese(number_script, FractionMode::SmallUnit, value.fraction())
+                        .as_str(),
+                );
+            }
+
+            Ok(s)
+        },
+    }
+}
+
+/// 將分數轉成中文，例如 **「三分之一」**、**「三分の一」**、**「삼분의 일」**。分子為負數時，負號放在最前面。
+///
+/// ## 範例
+///
+/// ```rust
+/// use conjak_numbers::{fraction_to_chinese, ChineseCountMethod, NumberScript, ScriptStyle};
+///
+/// let korean = NumberScript::Korean(ScriptStyle::Lower { circle_as_zero: false });
+///
+/// assert_eq!("삼분의 일", fraction_to_chinese(korean, ChineseCountMethod::TenThousand, 1, 3).unwrap());
+/// ```
+pub fn fraction_to_chinese(
+    number_script: NumberScript,
+    method: ChineseCountMethod,
+    numerator: i128,
+    denominator: u128,
+) -> Result<String, NumberToChineseError> {
+    if denominator == 0 {
+        return Err(NumberToChineseError::ZeroDenominator);
+    }
+
+    let numerator_str =
+        from_decimal_to_chinese(number_script, method, &Decimal::from(numerator.unsigned_abs()))?;
+    let denominator_str = from_decimal_to_chinese(number_script, method, &Decimal::from(denominator))?;
+
+    Ok(compose_fraction(
+        number_script,
+        numerator < 0,
+        denominator_str.as_str(),
+        numerator_str.as_str(),
+    ))
+}
+
+/// 將分數以阿拉伯數字寫出，例如 **「3分の1」**、**「3分之1」**、**「3분의 1」**。分子為負數時，負號放在最前面。
+///
+/// ## 範例
+///
+/// ```rust
+/// use conjak_numbers::{fraction_to_mixed, DigitWidth, NumberScript, ScriptStyle};
+///
+/// let japanese = NumberScript::Japanese(ScriptStyle::Lower { circle_as_zero: false });
+///
+/// assert_eq!("3分の1", fraction_to_mixed(japanese, DigitWidth::Half, 1, 3).unwrap());
+/// assert_eq!("－４分の３", fraction_to_mixed(japanese, DigitWidth::Full, -3, 4).unwrap());
+/// ```
+pub fn fraction_to_mixed(
+    number_script: NumberScript,
+    width: DigitWidth,
+    numerator: i128,
+    denominator: u128,
+) -> Result<String, NumberToChineseError> {
+    if denominator == 0 {
+        return Err(NumberToChineseError::ZeroDenominator);
+    }
+
+    let digits = |value: u128| -> String {
+        value.to_string().bytes().map(|b| width.digit(b - b'0')).collect()
+    };
+
+    let mut s = String::new();
+
+    if numerator < 0 {
+        s.push(match width {
+            DigitWidth::Half => '-',
+            DigitWidth::Full => '－',
+        });
+    }
+
+    s.push_str(digits(denominator).as_str());
+    s.push_str(fraction_connector(number_script));
+    s.push_str(digits(numerator.unsigned_abs()).as_str());
+
+    Ok(s)
+}
+
+/// 讓整數、浮點數與 `Decimal` 能當作比例轉成中文。
+pub trait NumberToRatio: IntoDecimal {
+    /// 將比例轉成中文，規則請見 `from_decimal_to_ratio`。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use conjak_numbers::{
+    ///     ChineseCountMethod, NumberScript, NumberToRatio, RatioStyle, ScriptStyle,
+    /// };
+    ///
+    /// let traditional = NumberScript::TraditionalChinese(ScriptStyle::Lower { circle_as_zero: false });
+    /// let japanese = NumberScript::Japanese(ScriptStyle::Lower { circle_as_zero: false });
+    ///
+    /// assert_eq!("百分之二十五點五", 0.255f64.to_ratio(traditional, ChineseCountMethod::TenThousand, RatioStyle::Percent).unwrap());
+    /// assert_eq!("千分之三", 0.003f64.to_ratio(traditional, ChineseCountMethod::TenThousand, RatioStyle::Permille).unwrap());
+    /// assert_eq!("二割五分", 0.25f64.to_ratio(japanese, ChineseCountMethod::TenThousand, RatioStyle::Wari).unwrap());
+    /// ```
+    fn to_ratio(
+        self,
+        number_script: NumberScript,
+        method: ChineseCountMethod,
+        style: RatioStyle,
+    ) -> Result<String, NumberToChineseError> {
+        from_decimal_to_ratio(number_script, method, style, &*self.into_decimal()?)
+    }
+}
+
+impl<T: IntoDecimal> NumberToRatio for T {}
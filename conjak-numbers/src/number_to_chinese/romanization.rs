@@ -0,0 +1,274 @@
+use alloc::string::String;
+
+use serde::Deserialize;
+
+use super::{
+    check_f64_range, decimal_to_numerals, f64_to_numerals,
+    functions::{writes_liang, Numeral},
+};
+use crate::{
+    ChineseCountMethod, ChineseExponent, ChineseFractionUnit, ChineseNumber, ChineseSign, Decimal,
+    FractionFormat, FractionMode, LocaleRules, NumberScript, NumberToChineseError, RoundingMode,
+};
+
+/// 中文數字的拼音方案。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RomanizationSystem {
+    /// 以調號標示聲調的漢語拼音，例如 **「yì bǎi èr shí sān」**。
+    #[default]
+    PinyinToneMark,
+    /// 以數字標示聲調的漢語拼音，例如 **「yi4 bai3 er4 shi2 san1」**。
+    PinyinToneNumber,
+    /// 粵語的粵拼，例如 **「jat1 baak3 ji6 sap6 saam1」**。
+    Jyutping,
+}
+
+/// 取得一個字的漢語拼音與粵拼，以及漢語拼音的聲調。**「兩」** 只在書寫方式會寫成 **「兩」** 時讀作 **「liǎng」**。
+const fn syllable(
+    numeral: Numeral,
+    chinese_variant: NumberScript,
+) -> (&'static str, u8, &'static str) {
+    match numeral {
+        Numeral::Number(number) => match number {
+            ChineseNumber::零 => ("ling", 2, "ling4"),
+            ChineseNumber::一 => ("yi", 1, "jat1"),
+            ChineseNumber::二 => ("er", 4, "ji6"),
+            ChineseNumber::三 => ("san", 1, "saam1"),
+            ChineseNumber::四 => ("si", 4, "sei3"),
+            ChineseNumber::五 => ("wu", 3, "ng5"),
+            ChineseNumber::六 => ("liu", 4, "luk6"),
+            ChineseNumber::七 => ("qi", 1, "cat1"),
+            ChineseNumber::八 => ("ba", 1, "baat3"),
+            ChineseNumber::九 => ("jiu", 3, "gau2"),
+            ChineseNumber::十 => ("shi", 2, "sap6"),
+        },
+        Numeral::Liang if writes_liang(chinese_variant) => ("liang", 3, "loeng5"),
+        Numeral::Liang => ("er", 4, "ji6"),
+        Numeral::Exponent(exponent) => match exponent {
+            ChineseExponent::分 => ("fen", 1, "fan1"),
+            ChineseExponent::角 => ("jiao", 3, "gok3"),
+            ChineseExponent::個 => ("ge", 4, "go3"),
+            ChineseExponent::十 => ("shi", 2, "sap6"),
+            ChineseExponent::百 => ("bai", 3, "baak3"),
+            ChineseExponent::千 => ("qian", 1, "cin1"),
+            ChineseExponent::萬 => ("wan", 4, "maan6"),
+            ChineseExponent::億 => ("yi", 4, "jik1"),
+            ChineseExponent::兆 => ("zhao", 4, "siu6"),
+            ChineseExponent::京 => ("jing", 1, "ging1"),
+            ChineseExponent::垓 => ("gai", 1, "goi1"),
+            ChineseExponent::秭 => ("zi", 3, "zi2"),
+            ChineseExponent::穰 => ("rang", 2, "joeng4"),
+            ChineseExponent::溝 => ("gou", 1, "kau1"),
+            ChineseExponent::澗 => ("jian", 4, "gaan3"),
+            ChineseExponent::正 => ("zheng", 4, "zing3"),
+            ChineseExponent::載 => ("zai", 4, "zoi3"),
+            ChineseExponent::極 => ("ji", 2, "gik6"),
+        },
+        Numeral::FractionUnit(unit) => match unit {
+            ChineseFractionUnit::分 => ("fen", 1, "fan1"),
+            ChineseFractionUnit::釐 => ("li", 2, "lei4"),
+            ChineseFractionUnit::毫 => ("hao", 2, "hou4"),
+            ChineseFractionUnit::絲 => ("si", 1, "si1"),
+            ChineseFractionUnit::忽 => ("hu", 1, "fat1"),
+            ChineseFractionUnit::微 => ("wei", 1, "mei4"),
+            ChineseFractionUnit::纖 => ("xian", 1, "cim1"),
+            ChineseFractionUnit::沙 => ("sha", 1, "saa1"),
+            ChineseFractionUnit::塵 => ("chen", 2, "can4"),
+            ChineseFractionUnit::埃 => ("ai", 1, "aai1"),
+            ChineseFractionUnit::渺 => ("miao", 3, "miu5"),
+            ChineseFractionUnit::漠 => ("mo", 4, "mok6"),
+        },
+        Numeral::Point => ("dian", 3, "dim2"),
+        Numeral::Sign(ChineseSign::正) => ("zheng", 4, "zing3"),
+        Numeral::Sign(ChineseSign::負) => ("fu", 4, "fu6"),
+    }
+}
+
+/// 在拼音的主要元音上加上調號。
+fn push_pinyin_with_tone_mark(s: &mut String, base: &str, tone: u8) {
+    const MARKS: [[char; 4]; 5] = [
+        ['ā', 'á', 'ǎ', 'à'],
+        ['ē', 'é', 'ě', 'è'],
+        ['ī', 'í', 'ǐ', 'ì'],
+        ['ō', 'ó', 'ǒ', 'ò'],
+        ['ū', 'ú', 'ǔ', 'ù'],
+    ];
+
+    // 有 a 或 e 時標在它們上面，ou 標在 o 上，其他情況標在最後一個元音上
+    let index = base
+        .find('a')
+        .or_else(|| base.find('e'))
+        .or_else(|| base.find("ou"))
+        .or_else(|| base.rfind(['i', 'o', 'u']));
+
+    for (i, c) in base.char_indices() {
+        match (Some(i) == index, "aeiou".find(c)) {
+            (true, Some(v)) => s.push(MARKS[v][tone as usize - 1]),
+            _ => s.push(c),
+        }
+    }
+}
+
+/// 將中文數字逐字轉成拼音，以空白分隔音節。
+///
+/// 漢語拼音會套用「一」的變調：「一」作為單位的係數時，在第四聲前讀第二聲，在其他聲調前讀第四聲，例如 **「yí wàn」**、**「yì bǎi」**；逐位讀出的數字、個位數與 **「十一」** 這類寫法則保持第一聲。
+fn numerals_to_romanization(
+    chinese_variant: NumberScript,
+    numerals: &[Numeral],
+    system: RomanizationSystem,
+) -> String {
+    let mut s = String::new();
+
+    for (i, &numeral) in numerals.iter().enumerate() {
+        let (pinyin, mut tone, jyutping) = syllable(numeral, chinese_variant);
+
+        if !s.is_empty() {
+            s.push(' ');
+        }
+
+        if system == RomanizationSystem::Jyutping {
+            s.push_str(jyutping);
+
+            continue;
+        }
+
+        if numeral == Numeral::Number(ChineseNumber::一)
+            && !matches!(
+                i.checked_sub(1).map(|p| numerals[p]),
+                Some(Numeral::Exponent(ChineseExponent::十) | Numeral::Point)
+            )
+            && let Some(&next @ (Numeral::Exponent(_) | Numeral::FractionUnit(_))) =
+                numerals.get(i + 1)
+        {
+            let (_, next_tone, _) = syllable(next, chinese_variant);
+
+            tone = if next_tone == 4 { 2 } else { 4 };
+        }
+
+        match system {
+            RomanizationSystem::PinyinToneMark => push_pinyin_with_tone_mark(&mut s, pinyin, tone),
+            _ => {
+                s.push_str(pinyin);
+                s.push((b'0' + tone) as char);
+            },
+        }
+    }
+
+    s
+}
+
+#[inline]
+fn check_romanization_script(number_script: NumberScript) -> Result<(), NumberToChineseError> {
+    match number_script {
+        NumberScript::SimplifiedChinese(_) | NumberScript::TraditionalChinese(_) => Ok(()),
+        _ => Err(NumberToChineseError::UnsupportedNumberScript),
+    }
+}
+
+/// 將十進位數值轉成中文數字的拼音。
+pub fn from_decimal_to_romanization(
+    number_script: NumberScript,
+    method: ChineseCountMethod,
+    system: RomanizationSystem,
+    value: &Decimal,
+) -> Result<String, NumberToChineseError> {
+    check_romanization_script(number_script)?;
+
+    let numerals = decimal_to_numerals(
+        number_script,
+        method,
+        LocaleRules::default(),
+        value,
+        FractionFormat::new(FractionMode::DecimalPoint, usize::MAX, RoundingMode::Truncate),
+    )?;
+
+    Ok(numerals_to_romanization(number_script, &numerals, system))
+}
+
+/// 讓整數、浮點數與 `Decimal` 能轉成中文數字的拼音。
+pub trait NumberToRomanization {
+    /// 將數值轉成中文數字後，再轉成漢語拼音或粵拼。只支援簡體中文與繁體中文，數值範圍的限制與 `to_chinese` 相同。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use conjak_numbers::{
+    ///     ChineseCountMethod, NumberScript, NumberToRomanization, RomanizationSystem, ScriptStyle,
+    /// };
+    ///
+    /// let script = NumberScript::SimplifiedChinese(ScriptStyle::Lower { circle_as_zero: false });
+    ///
+    /// assert_eq!("yì bǎi èr shí sān", 123u8.to_romanization(script, ChineseCountMethod::TenThousand, RomanizationSystem::PinyinToneMark).unwrap());
+    /// assert_eq!("yi2 wan4", 10000u16.to_romanization(script, ChineseCountMethod::TenThousand, RomanizationSystem::PinyinToneNumber).unwrap());
+    /// assert_eq!("jat1 baak3 ji6 sap6 saam1", 123u8.to_romanization(script, ChineseCountMethod::TenThousand, RomanizationSystem::Jyutping).unwrap());
+    /// ```
+    fn to_romanization(
+        self,
+        number_script: NumberScript,
+        method: ChineseCountMethod,
+        system: RomanizationSystem,
+    ) -> Result<String, NumberToChineseError>;
+}
+
+macro_rules! impl_number_to_romanization_for_integer {
+    ($($t:ty),* $(,)*) => {
+        $(
+            impl NumberToRomanization for $t {
+                #[inline]
+                fn to_romanization(
+                    self,
+                    number_script: NumberScript,
+                    method: ChineseCountMethod,
+                    system: RomanizationSystem,
+                ) -> Result<String, NumberToChineseError> {
+                    from_decimal_to_romanization(number_script, method, system, &Decimal::from(self))
+                }
+            }
+        )*
+    };
+}
+
+impl_number_to_romanization_for_integer!(
+    u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize
+);
+
+macro_rules! impl_number_to_romanization_for_float {
+    ($($t:ty),* $(,)*) => {
+        $(
+            impl NumberToRomanization for $t {
+                /// 與 `to_chinese` 相同，小數部分讀作 **「角」**、**「分」**。
+                #[inline]
+                fn to_romanization(
+                    self,
+                    number_script: NumberScript,
+                    method: ChineseCountMethod,
+                    system: RomanizationSystem,
+                ) -> Result<String, NumberToChineseError> {
+                    check_romanization_script(number_script)?;
+                    check_f64_range(method, self as f64)?;
+
+                    Ok(numerals_to_romanization(
+                        number_script,
+                        &f64_to_numerals(method, self as f64),
+                        system,
+                    ))
+                }
+            }
+        )*
+    };
+}
+
+impl_number_to_romanization_for_float!(f32, f64);
+
+impl NumberToRomanization for &Decimal {
+    #[inline]
+    fn to_romanization(
+        self,
+        number_script: NumberScript,
+        method: ChineseCountMethod,
+        system: RomanizationSystem,
+    ) -> Result<String, NumberToChineseError> {
+        from_decimal_to_romanization(number_script, method, system, self)
+    }
+}
@@ -0,0 +1,97 @@
+use alloc::string::String;
+
+use super::{
+    check_decimal_range, from_decimal_to_chinese,
+    functions::{
+        big_unsigned_integer_to_numeral_groups_ten_thousand, fraction_digits_to_chinese,
+        numerals_to_chinese,
+    },
+    IntoDecimal,
+};
+use crate::{
+    chinese_characters::ChineseSign, ChineseCountMethod, Decimal, LocaleRules, NumberScript,
+    NumberToChineseError, SpacingFormat,
+};
+
+/// 將十進位數值轉成中文數字，並依照 `spacing` 在萬進單位後面加上分隔符號、省略 **「一」** 與 **「零」**，例如 **「십이억 삼천사백오십육만 칠천팔백구십」**。
+///
+/// 只有 **「萬進」** 會套用 `spacing`，其他算法與 `from_decimal_to_chinese` 相同。小數部分以小數點逐位讀出。數值範圍的限制與 `from_decimal_to_chinese` 相同。
+pub fn from_decimal_to_chinese_with_spacing(
+    number_script: NumberScript,
+    method: ChineseCountMethod,
+    spacing: SpacingFormat,
+    value: &Decimal,
+) -> Result<String, NumberToChineseError> {
+    if method != ChineseCountMethod::TenThousand {
+        return from_decimal_to_chinese(number_script, method, value);
+    }
+
+    check_decimal_range(method, value)?;
+
+    let mut s = String::new();
+
+    if value.is_negative() {
+        s.push_str(ChineseSign::負.to_str(number_script));
+    }
+
+    let groups = big_unsigned_integer_to_numeral_groups_ten_thousand(
+        LocaleRules::default(),
+        false,
+        spacing,
+        value.integer().clone(),
+    );
+
+    for (i, group) in groups.iter().enumerate() {
+        if i > 0
+            && let Some(separator) = spacing.separator
+        {
+            s.push(separator);
+        }
+
+        s.push_str(numerals_to_chinese(number_script, group).as_str());
+    }
+
+    if !value.fraction().is_empty() {
+        s.push_str(fraction_digits_to_chinese(number_script, value.fraction()).as_str());
+    }
+
+    Ok(s)
+}
+
+/// 讓整數、浮點數與 `Decimal` 能轉成在萬進單位之間分隔的中文數字。
+pub trait NumberToChineseWithSpacing: IntoDecimal {
+    /// 將數值轉成在萬進單位之間分隔的中文數字，規則請見 `from_decimal_to_chinese_with_spacing`。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use conjak_numbers::{
+    ///     ChineseCountMethod, NumberScript, NumberToChineseWithSpacing, ScriptStyle, SpacingFormat,
+    /// };
+    ///
+    /// let korean = NumberScript::Korean(ScriptStyle::Lower { circle_as_zero: false });
+    ///
+    /// assert_eq!(
+    ///     "십이억 삼천사백오십육만 칠천팔백구십",
+    ///     1234567890u32
+    ///         .to_chinese_with_spacing(korean, ChineseCountMethod::TenThousand, SpacingFormat::korean())
+    ///         .unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "만 천백십일",
+    ///     11111u32
+    ///         .to_chinese_with_spacing(korean, ChineseCountMethod::TenThousand, SpacingFormat::korean())
+    ///         .unwrap()
+    /// );
+    /// ```
+    fn to_chinese_with_spacing(
+        self,
+        number_script: NumberScript,
+        method: ChineseCountMethod,
+        spacing: SpacingFormat,
+    ) -> Result<String, NumberToChineseError> {
+        from_decimal_to_chinese_with_spacing(number_script, method, spacing, &*self.into_decimal()?)
+    }
+}
+
+impl<T: IntoDecimal> NumberToChineseWithSpacing for T {}
@@ -0,0 +1,163 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use super::IntoDecimal;
+use crate::{
+    chinese_characters::{ChineseExponent, ChineseFractionUnit},
+    Decimal, NumberScript, NumberToChineseError, ScriptStyle,
+};
+
+/// 蘇州碼子的直寫數字，依序為〇到九。
+const SUZHOU_DIGITS: [char; 10] = ['〇', '〡', '〢', '〣', '〤', '〥', '〦', '〧', '〨', '〩'];
+
+/// 一到三的橫寫數字，用來和相鄰的直寫數字區別。
+const SUZHOU_HORIZONTAL_DIGITS: [char; 3] = ['一', '二', '三'];
+
+/// 蘇州碼子的寫法，分成數字與位數兩行，例如 **「〤〇〢」** 的下方寫 **「百」** 表示 402。
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct SuzhouNumeral {
+    /// 數字，從第一個不是零的數字寫到最後一個不是零的數字，例如 **「〡二〣」**。
+    pub digits: String,
+    /// 第一個數字所在的位數，例如 **「百」**、**「十萬」**、**「分」**。第一個數字在個位時為空字串。
+    pub magnitude: String,
+}
+
+fn place_to_magnitude(number_script: NumberScript, place: isize) -> Result<String, NumberToChineseError> {
+    let mut s = String::new();
+
+    if place < 0 {
+        let index = match u8::try_from(-(place + 1)) {
+            Ok(index) if index <= ChineseFractionUnit::漠.ordinal() => index,
+            _ => return Err(NumberToChineseError::Underflow),
+        };
+
+        s.push_str(
+            unsafe { ChineseFractionUnit::from_ordinal_unsafe(index) }.to_str(number_script),
+        );
+    } else if place > 0 {
+        let group = match u8::try_from(place / 4) {
+            Ok(group) if group <= ChineseExponent::極.ordinal() - ChineseExponent::萬.ordinal() + 1 => {
+                group
+            },
+            _ => return Err(NumberToChineseError::Overflow),
+        };
+        let rem = (place % 4) as u8;
+
+        if rem > 0 {
+            s.push_str(
+                unsafe { ChineseExponent::from_ordinal_unsafe(ChineseExponent::個.ordinal() + rem) }
+                    .to_str(number_script),
+            );
+        }
+
+        if group > 0 {
+            s.push_str(
+                unsafe {
+                    ChineseExponent::from_ordinal_unsafe(ChineseExponent::萬.ordinal() + group - 1)
+                }
+                .to_str(number_script),
+            );
+        }
+    }
+
+    Ok(s)
+}
+
+/// 將十進位數值轉成蘇州碼子，例如 **「〡二〣」**（百）表示 123。
+///
+/// * 數字與數字之間的零寫成 **「〇」**，最後面的零則省略，由位數表示，例如 4500 寫成 **「〤〥」**（千）。
+/// * 一、二、三連續出現時，第二個數字改用橫寫，例如 **「〡一」**（十）表示 11、**「〢三」**（百）表示 230。
+/// * 位數使用 **「萬進」** 的單位，小數則使用 **「分」**、**「釐」**、**「毫」**……等單位。
+///
+/// 只支援簡體中文與繁體中文，不支援負數。
+pub fn from_decimal_to_suzhou(
+    number_script: NumberScript,
+    value: &Decimal,
+) -> Result<SuzhouNumeral, NumberToChineseError> {
+    let number_script = match number_script {
+        NumberScript::SimplifiedChinese(_) | NumberScript::TraditionalChinese(_) => {
+            number_script.with_style(ScriptStyle::Lower {
+                circle_as_zero: false,
+            })
+        },
+        _ => return Err(NumberToChineseError::UnsupportedNumberScript),
+    };
+
+    if value.is_negative() {
+        return Err(NumberToChineseError::Underflow);
+    }
+
+    let integer = value.integer().to_string();
+
+    // 整數部分為零時，小數的第一位就在「分」位
+    let integer_length = if integer == "0" { 0 } else { integer.len() };
+
+    let mut digits: Vec<u8> = integer.bytes().map(|b| b - b'0').collect();
+    digits.truncate(integer_length);
+    digits.extend_from_slice(value.fraction());
+
+    let (first, last) = match (
+        digits.iter().position(|&d| d > 0),
+        digits.iter().rposition(|&d| d > 0),
+    ) {
+        (Some(first), Some(last)) => (first, last),
+        _ => {
+            return Ok(SuzhouNumeral {
+                digits: SUZHOU_DIGITS[0].to_string(),
+                magnitude: String::new(),
+            });
+        },
+    };
+
+    let magnitude = place_to_magnitude(number_script, integer_length as isize - 1 - first as isize)?;
+
+    let mut s = String::new();
+    let mut previous_vertical_low = false;
+
+    for &d in &digits[first..=last] {
+        if (1..=3).contains(&d) && previous_vertical_low {
+            s.push(SUZHOU_HORIZONTAL_DIGITS[d as usize - 1]);
+
+            previous_vertical_low = false;
+        } else {
+            s.push(SUZHOU_DIGITS[d as usize]);
+
+            previous_vertical_low = (1..=3).contains(&d);
+        }
+    }
+
+    Ok(SuzhouNumeral {
+        digits: s,
+        magnitude,
+    })
+}
+
+/// 讓整數、浮點數與 `Decimal` 能轉成蘇州碼子。
+pub trait NumberToSuzhou: IntoDecimal {
+    /// 將數值轉成蘇州碼子，規則請見 `from_decimal_to_suzhou`。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use conjak_numbers::{NumberScript, NumberToSuzhou, ScriptStyle};
+    ///
+    /// let script = NumberScript::TraditionalChinese(ScriptStyle::Lower { circle_as_zero: false });
+    ///
+    /// let numeral = 123u8.to_suzhou(script).unwrap();
+    ///
+    /// assert_eq!("〡二〣", numeral.digits);
+    /// assert_eq!("百", numeral.magnitude);
+    ///
+    /// let numeral = 150000u32.to_suzhou(script).unwrap();
+    ///
+    /// assert_eq!("〡〥", numeral.digits);
+    /// assert_eq!("十萬", numeral.magnitude);
+    /// ```
+    fn to_suzhou(self, number_script: NumberScript) -> Result<SuzhouNumeral, NumberToChineseError> {
+        from_decimal_to_suzhou(number_script, &*self.into_decimal()?)
+    }
+}
+
+impl<T: IntoDecimal> NumberToSuzhou for T {}
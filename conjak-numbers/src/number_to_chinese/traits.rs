@@ -1,7 +1,8 @@
 use super::*;
+use crate::{Decimal, FractionFormat, LocaleRules};
 
 /// 讓 Rust 程式語言的所有基本數值型別擁有轉成中文數字的能力。
-pub trait NumberToChinese {
+pub trait NumberToChinese: IntoDecimal {
     /// 將數值轉成中文數字。
     ///
     /// * 如果使用 **「下數」**，則數值的絕對值不能大於或等於 `1_0000_0000_0000_0000`。
@@ -37,6 +38,94 @@ pub trait NumberToChinese {
     /// );
     /// ```
     fn to_chinese_naive(self, number_script: NumberScript) -> String;
+
+    /// 將數值轉成中文數字，小數部分依 `fraction` 指定的讀法、位數與捨入方式輸出。數值範圍的限制與 `to_chinese` 相同，並以捨入後的數值判斷。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use conjak_numbers::{
+    ///     ChineseCountMethod, FractionFormat, FractionMode, NumberScript, NumberToChinese,
+    ///     RoundingMode, ScriptStyle,
+    /// };
+    ///
+    /// let script = NumberScript::TraditionalChinese(ScriptStyle::Lower { circle_as_zero: false });
+    ///
+    /// assert_eq!(
+    ///     "一點二三四六",
+    ///     1.23456f64
+    ///         .to_chinese_with_fraction(
+    ///             script,
+    ///             ChineseCountMethod::TenThousand,
+    ///             FractionFormat::new(FractionMode::DecimalPoint, 4, RoundingMode::HalfUp)
+    ///         )
+    ///         .unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "三分一釐四毫",
+    ///     0.314f64
+    ///         .to_chinese_with_fraction(
+    ///             script,
+    ///             ChineseCountMethod::TenThousand,
+    ///             FractionFormat::new(FractionMode::SmallUnit, 6, RoundingMode::HalfUp)
+    ///         )
+    ///         .unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "十二三角四分五釐",
+    ///     12.3456f64
+    ///         .to_chinese_with_fraction(
+    ///             script,
+    ///             ChineseCountMethod::TenThousand,
+    ///             FractionFormat::new(FractionMode::Currency, 3, RoundingMode::Truncate)
+    ///         )
+    ///         .unwrap()
+    /// );
+    /// ```
+    #[inline]
+    fn to_chinese_with_fraction(
+        self,
+        number_script: NumberScript,
+        method: ChineseCountMethod,
+        fraction: FractionFormat,
+    ) -> Result<String, NumberToChineseError> {
+        from_decimal_to_chinese_with_fraction(number_script, method, &*self.into_decimal()?, fraction)
+    }
+
+    /// 將數值轉成中文數字，**「一」** 的省略與 **「兩」** 的使用依照 `rules`，例如各地區的寫法可以用 `LocalePreset` 取得。小數部分依 `fraction` 輸出，`None` 表示與 `to_chinese` 相同的讀法。數值範圍的限制與 `to_chinese_with_fraction` 相同。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use conjak_numbers::{
+    ///     ChineseCountMethod, LocalePreset, NumberScript, NumberToChinese, ScriptStyle,
+    /// };
+    ///
+    /// let simplified = NumberScript::SimplifiedChinese(ScriptStyle::Lower { circle_as_zero: false });
+    /// let traditional = NumberScript::TraditionalChinese(ScriptStyle::Lower { circle_as_zero: false });
+    /// let japanese = NumberScript::Japanese(ScriptStyle::Lower { circle_as_zero: false });
+    ///
+    /// assert_eq!("两千二百", 2200.to_chinese_with_rules(simplified, ChineseCountMethod::TenThousand, LocalePreset::Cn.rules(), None).unwrap());
+    /// assert_eq!("二千二百", 2200.to_chinese_with_rules(traditional, ChineseCountMethod::TenThousand, LocalePreset::Tw.rules(), None).unwrap());
+    /// assert_eq!("兩百", 200.to_chinese_with_rules(traditional, ChineseCountMethod::TenThousand, LocalePreset::Hk.rules(), None).unwrap());
+    /// assert_eq!("千百十", 1110.to_chinese_with_rules(japanese, ChineseCountMethod::TenThousand, LocalePreset::Jp.rules(), None).unwrap());
+    /// ```
+    #[inline]
+    fn to_chinese_with_rules(
+        self,
+        number_script: NumberScript,
+        method: ChineseCountMethod,
+        rules: LocaleRules,
+        fraction: Option<FractionFormat>,
+    ) -> Result<String, NumberToChineseError> {
+        from_decimal_to_chinese_with_rules(
+            number_script,
+            method,
+            rules,
+            &*self.into_decimal()?,
+            fraction.unwrap_or(Self::DEFAULT_FRACTION),
+        )
+    }
 }
 
 impl NumberToChinese for u8 {
@@ -303,3 +392,22 @@ impl NumberToChinese for f64 {
         from_f64_to_chinese_naive(number_script, self)
     }
 }
+
+impl NumberToChinese for &Decimal {
+    #[inline]
+    fn to_chinese(
+        self,
+        number_script: NumberScript,
+        method: ChineseCountMethod,
+    ) -> Result<String, NumberToChineseError> {
+        from_decimal_to_chinese(number_script, method, self)
+    }
+
+    #[inline]
+    fn to_chinese_naive(
+        self,
+        number_script: NumberScript,
+    ) -> String {
+        from_decimal_to_chinese_naive(number_script, self)
+    }
+}
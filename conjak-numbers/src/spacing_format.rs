@@ -0,0 +1,39 @@
+use serde::Deserialize;
+
+/// 萬進單位之間的分隔與 **「一」**、**「零」** 的省略方式，例如韓文的 **「십이억 삼천사백오십육만 칠천팔백구십」**。
+///
+/// 預設值為不分隔、不省略，與一般的寫法相同。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[serde(default)]
+pub struct SpacingFormat {
+    /// 寫在 **「萬」**、**「億」**、**「兆」**……等單位後面的分隔符號，例如空白。
+    pub separator: Option<char>,
+    /// 省略 **「十」**、**「百」**、**「千」** 前面的 **「一」**，單獨一萬也只寫 **「萬」**，例如 **「만 천백십일」**，但 **「零」** 後面仍寫 **「一」**，例如 **「一亿 零一万」**。**「億」** 以上的單位前面仍然寫 **「一」**。
+    pub omit_leading_one: bool,
+    /// 省略整數部分的 **「零」**，例如韓文的 **「천오」**、**「일억 오」**。數值為零時仍寫 **「零」**。
+    pub omit_zero: bool,
+}
+
+impl SpacingFormat {
+    #[inline]
+    pub const fn new(separator: Option<char>, omit_leading_one: bool, omit_zero: bool) -> Self {
+        SpacingFormat {
+            separator,
+            omit_leading_one,
+            omit_zero,
+        }
+    }
+
+    /// 韓文正書法（한글 맞춤법）的寫法，以空白分隔萬進單位，並省略 **「일」** 與 **「영」**。
+    #[inline]
+    pub const fn korean() -> Self {
+        SpacingFormat::new(Some(' '), true, true)
+    }
+}
+
+impl Default for SpacingFormat {
+    #[inline]
+    fn default() -> Self {
+        SpacingFormat::new(None, false, false)
+    }
+}
@@ -0,0 +1,229 @@
+use assert_eq_float::assert_eq_float;
+use conjak_numbers::{
+    ChineseCountMethod, ChineseToNumber, ChineseToNumberError, NumberScript, NumberToChinese,
+    ScriptStyle,
+};
+
+macro_rules! test_group {
+    ($method:expr) => {
+        macro_rules! test {
+            ($expect: expr, $value: expr) => {
+                assert_eq!(Ok($expect), $value.to_number($method));
+            };
+        }
+
+        #[allow(unused)]
+        macro_rules! test_float {
+            ($expect: expr, $value: expr) => {
+                assert_eq_float!($expect, $value.to_number($method).unwrap());
+            };
+        }
+
+        #[allow(unused)]
+        macro_rules! test_err {
+            ($expect: expr, $t: ty, $value: expr) => {
+                assert_eq!(Err($expect), ChineseToNumber::<$t>::to_number($value, $method));
+            };
+        }
+    };
+}
+
+#[test]
+fn to_number_low() {
+    test_group!(ChineseCountMethod::Low);
+
+    test!(127i8, "一百二十七");
+    test!(-128i8, "負一百二十八");
+    test!(1000000u64, "一兆");
+    test!(1234567890123456i64, "一極二載三正四澗五溝六穰七秭八垓九京零一億二萬三千四百五十六");
+    test!(150000u64, "一億五");
+
+    test_err!(ChineseToNumberError::Overflow, u8, "二百五十六");
+    test_err!(ChineseToNumberError::Underflow, i8, "負一百二十九");
+}
+
+#[test]
+fn to_number_ten_thousand() {
+    test_group!(ChineseCountMethod::TenThousand);
+
+    test!(123i8, "一百二十三");
+    test!(-30303i16, "負三萬零三百零三");
+    test!(3212345678u32, "三十二億一千二百三十四萬五千六百七十八");
+    test!(10010001001001001000u64, "一千零一京零一兆零一十億零一百萬一千");
+    test!(1000000000000u64, "一兆");
+    test!(i64::MAX, "九百二十二京三千三百七十二兆零三百六十八億五千四百七十七萬五千八百零七");
+    test!(i128::MIN, "負一百七十澗一千四百一十一溝八千三百四十六穰零四百六十九秭二千三百一十七垓三千一百六十八京七千三百零三兆七千一百五十八億八千四百一十萬五千七百二十八");
+
+    test!(120u64, "一百二");
+    test!(2300u64, "兩千三");
+    test!(34000u64, "三萬四");
+    test!(105000u64, "十萬五");
+    test!(150000000u64, "一億五");
+    test!(1010u16, "一千零十");
+
+    test_float!(0.12f64, "一角二分");
+    test_float!(99.99f64, "九十九九角九分");
+    test_float!(-123.46f64, "負一百二十三四角六分");
+    test_float!(3.0625f64, "三點零六二五");
+    test_float!(0.5f32, "五角");
+
+    test_err!(ChineseToNumberError::ChineseNumberEmpty, u32, "");
+    test_err!(ChineseToNumberError::ChineseNumberEmpty, i32, "負");
+    test_err!(ChineseToNumberError::ChineseNumberIncorrect { char_index: 1 }, u32, "一二");
+    test_err!(ChineseToNumberError::ChineseNumberIncorrect { char_index: 2 }, u32, "一百a");
+    test_err!(ChineseToNumberError::ChineseNumberIncorrect { char_index: 1 }, u32, "三點一四");
+    test_err!(ChineseToNumberError::ChineseNumberIncorrect { char_index: 3 }, u64, "一萬一萬");
+    test_err!(ChineseToNumberError::Overflow, u64, "一千八百四十五京");
+    test_err!(ChineseToNumberError::Underflow, u64, "負一");
+}
+
+#[test]
+fn to_number_middle() {
+    test_group!(ChineseCountMethod::Middle);
+
+    test!(10000000000000000u64, "一兆");
+    test!(u64::MAX, "一千八百四十四兆六千七百四十四萬零七百三十七億零九百五十五萬一千六百一十五");
+    test!(u128::MAX, "三百四十萬二千八百二十三垓六千六百九十二萬零九百三十八京四千六百三十四萬六千三百三十七兆四千六百零七萬四千三百一十七億六千八百二十一萬一千四百五十五");
+}
+
+#[test]
+fn to_number_high() {
+    test_group!(ChineseCountMethod::High);
+
+    test!(10000000000000000u64, "一兆");
+    test!(u64::MAX, "一千八百四十四兆六千七百四十四萬零七百三十七億零九百五十五萬一千六百一十五");
+    test!(u128::MAX, "三百四十萬二千八百二十三京六千六百九十二萬零九百三十八億四千六百三十四萬六千三百三十七兆四千六百零七萬四千三百一十七億六千八百二十一萬一千四百五十五");
+}
+
+#[test]
+fn to_number_scripts() {
+    test_group!(ChineseCountMethod::TenThousand);
+
+    test!(123u8, "壹佰貳拾參");
+    test!(123u8, "壹佰贰拾叁");
+    test!(123u8, "壱百弐拾参");
+    test!(-12345i32, "负一万二千三百四十五");
+    test!(30000u32, "삼만");
+    test!(123456789u32, "일억이천삼백사십오만육천칠백팔십구");
+    test!(11000u32, "만천");
+    test!(1200000000u32, "십이억 ");
+
+    test_float!(0.29f64, "영점이구");
+    test_float!(9.99f64, "구점구구");
+
+    test_err!(ChineseToNumberError::ChineseNumberIncorrect { char_index: 3 }, u32, "일만일만");
+}
+
+#[test]
+fn to_number_naive() {
+    assert_eq!(Ok(123i8), "一二三".to_number_naive());
+    assert_eq!(Ok(-128i8), "負一二八".to_number_naive());
+    assert_eq!(Ok(2025u16), "二〇二五".to_number_naive());
+    assert_eq!(Ok(99u8), "구구".to_number_naive());
+    assert_eq_float!(123.45f64, "壹貳參點肆伍".to_number_naive().unwrap());
+
+    assert_eq!(
+        Err(ChineseToNumberError::ChineseNumberIncorrect { char_index: 1 }),
+        ChineseToNumber::<u32>::to_number_naive("一十")
+    );
+    assert_eq!(Err(ChineseToNumberError::Overflow), ChineseToNumber::<u8>::to_number_naive("二五六"));
+}
+
+#[test]
+fn to_number_with_script() {
+    let method = ChineseCountMethod::TenThousand;
+    let simplified = NumberScript::SimplifiedChinese(ScriptStyle::Upper);
+    let japanese = NumberScript::Japanese(ScriptStyle::Lower { circle_as_zero: false });
+    let korean = NumberScript::Korean(ScriptStyle::Lower { circle_as_zero: false });
+
+    assert_eq!(Ok(123u8), "壹佰贰拾叁".to_number_with_script(simplified, method));
+    assert_eq!(Ok(123u8), "一百二十三".to_number_with_script(simplified, method));
+    assert_eq!(Ok(35000000u32), "三千五百万".to_number_with_script(japanese, method));
+    assert_eq!(Ok(30000u32), "삼만".to_number_with_script(korean, method));
+    assert_eq!(Ok(2025u16), "이영이오".to_number_naive_with_script(korean));
+
+    assert_eq!(
+        Err(ChineseToNumberError::ChineseNumberIncorrect { char_index: 1 }),
+        ChineseToNumber::<u32>::to_number_with_script("三만", japanese, method)
+    );
+    assert_eq!(
+        Err(ChineseToNumberError::ChineseNumberIncorrect { char_index: 0 }),
+        ChineseToNumber::<u32>::to_number_naive_with_script("二〇二五", korean)
+    );
+}
+
+#[test]
+fn round_trip() {
+    let scripts = [
+        NumberScript::SimplifiedChinese(ScriptStyle::Upper),
+        NumberScript::SimplifiedChinese(ScriptStyle::Lower { circle_as_zero: true }),
+        NumberScript::TraditionalChinese(ScriptStyle::Upper),
+        NumberScript::TraditionalChinese(ScriptStyle::Lower { circle_as_zero: false }),
+        NumberScript::Japanese(ScriptStyle::Upper),
+        NumberScript::Japanese(ScriptStyle::HistoricUpper),
+        NumberScript::Japanese(ScriptStyle::Lower { circle_as_zero: false }),
+        NumberScript::Korean(ScriptStyle::Upper),
+        NumberScript::Korean(ScriptStyle::Lower { circle_as_zero: false }),
+    ];
+
+    for script in scripts {
+        for method in ChineseCountMethod::variants() {
+            for value in [0i64, 7, 10, 19, 101, 1010, 10001, 99999999, -4567, 1234567890123, -9999999999999999] {
+                let s = value.to_chinese(script, method).unwrap();
+
+                assert_eq!(Ok(value), s.to_number(method), "{s}");
+            }
+        }
+
+        for value in [0u64, 9, 1000, 20250101, u64::MAX] {
+            let s = value.to_chinese_naive(script);
+
+            assert_eq!(Ok(value), s.to_number_naive(), "{s}");
+        }
+    }
+}
+
+#[test]
+fn round_trip_locale_rules() {
+    use conjak_numbers::LocalePreset;
+
+    let presets = [
+        (NumberScript::SimplifiedChinese(ScriptStyle::Lower { circle_as_zero: false }), LocalePreset::Cn),
+        (NumberScript::TraditionalChinese(ScriptStyle::Lower { circle_as_zero: false }), LocalePreset::Tw),
+        (NumberScript::TraditionalChinese(ScriptStyle::Lower { circle_as_zero: false }), LocalePreset::Hk),
+        (NumberScript::Japanese(ScriptStyle::Lower { circle_as_zero: false }), LocalePreset::Jp),
+    ];
+
+    for (script, preset) in presets {
+        for value in [0i64, 12, 110, 200, 1111, 2000, 22000, 10001, 2_0000_0000, -2345, 1234567890123] {
+            let s = value
+                .to_chinese_with_rules(script, ChineseCountMethod::TenThousand, preset.rules(), None)
+                .unwrap();
+
+            assert_eq!(Ok(value), s.to_number(ChineseCountMethod::TenThousand), "{s}");
+        }
+    }
+}
+
+#[test]
+fn detect_number_script() {
+    use conjak_numbers::{detect_number_script, is_naive_chinese_number};
+
+    let lower = ScriptStyle::Lower { circle_as_zero: false };
+
+    assert_eq!(Some(NumberScript::SimplifiedChinese(lower)), detect_number_script("一百二十三"));
+    assert_eq!(Some(NumberScript::SimplifiedChinese(ScriptStyle::Upper)), detect_number_script("壹佰贰拾叁"));
+    assert_eq!(Some(NumberScript::TraditionalChinese(lower)), detect_number_script("兩萬"));
+    assert_eq!(Some(NumberScript::Japanese(ScriptStyle::Upper)), detect_number_script("壱万"));
+    assert_eq!(Some(NumberScript::Japanese(ScriptStyle::HistoricUpper)), detect_number_script("肆阡伍陌"));
+    assert_eq!(Some(NumberScript::Korean(lower)), detect_number_script("삼만"));
+    assert_eq!(
+        Some(NumberScript::SimplifiedChinese(ScriptStyle::Lower { circle_as_zero: true })),
+        detect_number_script("二〇二五")
+    );
+    assert_eq!(None, detect_number_script("삼萬"));
+
+    assert!(is_naive_chinese_number("구구"));
+    assert!(is_naive_chinese_number("負一二點五"));
+    assert!(!is_naive_chinese_number("九十九九角九分"));
+}
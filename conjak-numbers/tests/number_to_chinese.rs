@@ -2,6 +2,12 @@ use conjak_numbers::{
     ChineseCountMethod, NumberScript, ScriptStyle, NumberToChinese, NumberToChineseError,
 };
 
+const SIMPLIFIED: NumberScript = NumberScript::SimplifiedChinese(ScriptStyle::Lower { circle_as_zero: false });
+const TRADITIONAL: NumberScript = NumberScript::TraditionalChinese(ScriptStyle::Lower { circle_as_zero: false });
+const JAPANESE: NumberScript = NumberScript::Japanese(ScriptStyle::Lower { circle_as_zero: false });
+const KOREAN: NumberScript = NumberScript::Korean(ScriptStyle::Lower { circle_as_zero: false });
+const VIETNAMESE: NumberScript = NumberScript::Vietnamese(ScriptStyle::Lower { circle_as_zero: false });
+
 macro_rules! test_group {
     ($script:expr, $method:expr) => {
         macro_rules! test {
@@ -35,6 +41,16 @@ macro_rules! test_group_naive {
     };
 }
 
+macro_rules! test_method {
+    ($method:ident) => {
+        macro_rules! test {
+            ($expect: expr, $value: expr, $args: tt) => {
+                assert_eq!($expect, $value.$method $args.unwrap());
+            };
+        }
+    };
+}
+
 #[test]
 fn to_uppercase_low() {
     test_group!(NumberScript::TraditionalChinese(ScriptStyle::Upper), ChineseCountMethod::Low);
@@ -347,3 +363,690 @@ fn to_uppercase_naive() {
     test!("壹柒玖柒陸玖參壹參肆捌陸貳參壹伍柒零捌壹肆伍貳柒肆貳參柒參壹柒零肆參伍陸柒玖捌零柒零伍陸柒伍貳伍捌肆肆玖玖陸伍玖捌玖壹柒肆柒陸捌零參壹伍柒貳陸零柒捌零零貳捌伍參捌柒陸零伍捌玖伍伍捌陸參貳柒陸陸捌柒捌壹柒壹伍肆零肆伍捌玖伍參伍壹肆參捌貳肆陸肆貳參肆參貳壹參貳陸捌捌玖肆陸肆壹捌貳柒陸捌肆陸柒伍肆陸柒零參伍參柒伍壹陸玖捌陸零肆玖玖壹零伍柒陸伍伍壹貳捌貳零柒陸貳肆伍肆玖零零玖零參捌玖參貳捌玖肆肆零柒伍捌陸捌伍零捌肆伍伍壹參參玖肆貳參零肆伍捌參貳參陸玖零參貳貳貳玖肆捌壹陸伍捌零捌伍伍玖參參貳壹貳參參肆捌貳柒肆柒玖柒捌貳陸貳零肆壹肆肆柒貳參壹陸捌柒參捌壹柒柒壹捌零玖壹玖貳玖玖捌捌壹貳伍零肆零肆零貳陸壹捌肆壹貳肆捌伍捌參陸捌",f64::MAX);
     test!("負壹柒玖柒陸玖參壹參肆捌陸貳參壹伍柒零捌壹肆伍貳柒肆貳參柒參壹柒零肆參伍陸柒玖捌零柒零伍陸柒伍貳伍捌肆肆玖玖陸伍玖捌玖壹柒肆柒陸捌零參壹伍柒貳陸零柒捌零零貳捌伍參捌柒陸零伍捌玖伍伍捌陸參貳柒陸陸捌柒捌壹柒壹伍肆零肆伍捌玖伍參伍壹肆參捌貳肆陸肆貳參肆參貳壹參貳陸捌捌玖肆陸肆壹捌貳柒陸捌肆陸柒伍肆陸柒零參伍參柒伍壹陸玖捌陸零肆玖玖壹零伍柒陸伍伍壹貳捌貳零柒陸貳肆伍肆玖零零玖零參捌玖參貳捌玖肆肆零柒伍捌陸捌伍零捌肆伍伍壹參參玖肆貳參零肆伍捌參貳參陸玖零參貳貳貳玖肆捌壹陸伍捌零捌伍伍玖參參貳壹貳參參肆捌貳柒肆柒玖柒捌貳陸貳零肆壹肆肆柒貳參壹陸捌柒參捌壹柒柒壹捌零玖壹玖貳玖玖捌捌壹貳伍零肆零肆零貳陸壹捌肆壹貳肆捌伍捌參陸捌",f64::MIN);
 }
+
+#[test]
+fn decimal() {
+    use conjak_numbers::{ChineseToNumber, Decimal, ParseDecimalError};
+
+    let script = NumberScript::TraditionalChinese(ScriptStyle::Lower { circle_as_zero: false });
+
+    macro_rules! test {
+        ($expect:expr, $method:expr, $value:expr) => {
+            assert_eq!($expect, (&$value.parse::<Decimal>().unwrap()).to_chinese(script, $method).unwrap());
+        };
+    }
+
+    test!("十二穰三千四百五十六秭七千八百九十垓一千二百三十四京五千六百七十八兆九千零一十二億三千四百五十六萬七千八百九十點一二五", ChineseCountMethod::TenThousand, "123456789012345678901234567890.125");
+    test!("負一百二十三點四五六", ChineseCountMethod::TenThousand, "-123.456");
+    test!("零點零零一", ChineseCountMethod::Middle, "0.001");
+    test!("零", ChineseCountMethod::Low, "-0.000");
+    test!("一極", ChineseCountMethod::Low, "1000000000000000");
+    test!("一萬京", ChineseCountMethod::High, "1000000000000000000000000000000000000");
+
+    let value: Decimal = "1.0000000000000000000000000000001".parse().unwrap();
+    assert_eq!("一點零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零一", (&value).to_chinese(script, ChineseCountMethod::TenThousand).unwrap());
+    assert_eq!("负一二三点四〇五", (&"-123.405".parse::<Decimal>().unwrap()).to_chinese_naive(NumberScript::SimplifiedChinese(ScriptStyle::Lower { circle_as_zero: true })));
+
+    assert_eq!(Err(NumberToChineseError::Overflow), (&"10000000000000000".parse::<Decimal>().unwrap()).to_chinese(script, ChineseCountMethod::Low));
+    assert_eq!(Err(NumberToChineseError::Underflow), (&format!("-1{}", "0".repeat(52)).parse::<Decimal>().unwrap()).to_chinese(script, ChineseCountMethod::TenThousand));
+
+    assert_eq!(Err(ParseDecimalError::Empty), "-.".parse::<Decimal>());
+    assert_eq!(Err(ParseDecimalError::InvalidDigit { char_index: 3 }), "1.2.3".parse::<Decimal>());
+
+    let parsed: Decimal = "負三點一四一五九二六五三五八九七九三二三八四六二六四三三八三二七九五".to_number(ChineseCountMethod::TenThousand).unwrap();
+    assert_eq!("-3.1415926535897932384626433832795", parsed.to_string());
+}
+
+#[test]
+#[should_panic]
+fn decimal_fraction_digit_out_of_range() {
+    use conjak_numbers::Decimal;
+
+    Decimal::new(false, 1u8.into(), vec![10]);
+}
+
+#[test]
+fn fraction_format() {
+    use conjak_numbers::{Decimal, FractionFormat, FractionMode, RoundingMode};
+
+    let method = ChineseCountMethod::TenThousand;
+
+    test_method!(to_chinese_with_fraction);
+
+    test!("一點二三四六", 1.23456f64, (TRADITIONAL, method, FractionFormat::new(FractionMode::DecimalPoint, 4, RoundingMode::HalfUp)));
+    test!("一點二三四五", 1.23456f64, (TRADITIONAL, method, FractionFormat::new(FractionMode::DecimalPoint, 4, RoundingMode::Truncate)));
+    test!("二", 1.5f32, (TRADITIONAL, method, FractionFormat::new(FractionMode::DecimalPoint, 0, RoundingMode::HalfEven)));
+    test!("二", 2.5f64, (TRADITIONAL, method, FractionFormat::new(FractionMode::DecimalPoint, 0, RoundingMode::HalfEven)));
+    test!("三", 2.01f64, (TRADITIONAL, method, FractionFormat::new(FractionMode::DecimalPoint, 0, RoundingMode::Up)));
+    test!("十二三角四分", 12.3456f64, (TRADITIONAL, method, FractionFormat::new(FractionMode::Currency, 2, RoundingMode::Truncate)));
+    test!("十二三角四分六釐", 12.3456f64, (TRADITIONAL, method, FractionFormat::new(FractionMode::Currency, 3, RoundingMode::HalfUp)));
+    test!("十二三角四分六釐", 12.3456f64, (TRADITIONAL, method, FractionFormat::new(FractionMode::Currency, 9, RoundingMode::HalfUp)));
+    test!("一百", 99.999f64, (TRADITIONAL, method, FractionFormat::new(FractionMode::Currency, 2, RoundingMode::HalfUp)));
+    test!("三分一釐四毫", 0.314f64, (TRADITIONAL, method, FractionFormat::new(FractionMode::SmallUnit, 6, RoundingMode::HalfUp)));
+    test!("負五分二毫", -0.5020f64, (TRADITIONAL, method, FractionFormat::new(FractionMode::SmallUnit, 3, RoundingMode::HalfUp)));
+    test!("一百二十三", 123u32, (TRADITIONAL, method, FractionFormat::new(FractionMode::SmallUnit, 3, RoundingMode::HalfUp)));
+    test!("負一萬", -10000i64, (TRADITIONAL, method, FractionFormat::new(FractionMode::DecimalPoint, 2, RoundingMode::HalfUp)));
+    test!("三點一四", &"3.14159".parse::<Decimal>().unwrap(), (TRADITIONAL, method, FractionFormat::new(FractionMode::DecimalPoint, 2, RoundingMode::HalfEven)));
+
+    assert_eq!("三钱四厘五毛", 0.345f64.to_chinese_with_fraction(JAPANESE, method, FractionFormat::new(FractionMode::Currency, 3, RoundingMode::HalfUp)).unwrap());
+
+    assert_eq!(Err(NumberToChineseError::Overflow), f64::INFINITY.to_chinese_with_fraction(TRADITIONAL, method, FractionFormat::default()));
+    assert_eq!(Err(NumberToChineseError::Underflow), f64::NEG_INFINITY.to_chinese_with_fraction(TRADITIONAL, method, FractionFormat::default()));
+    assert_eq!(Err(NumberToChineseError::Overflow), 9999999999999999.9f64.to_chinese_with_fraction(TRADITIONAL, ChineseCountMethod::Low, FractionFormat::new(FractionMode::DecimalPoint, 0, RoundingMode::Up)));
+}
+
+#[test]
+fn financial_amount() {
+    use conjak_numbers::{AmountCurrency, Decimal, NumberToFinancialAmount};
+
+    let simplified = NumberScript::SimplifiedChinese(ScriptStyle::Upper);
+    let method = ChineseCountMethod::TenThousand;
+
+    test_method!(to_financial_amount);
+
+    test!("人民币壹万贰仟叁佰肆拾伍元陆角柒分", 12345.67f64, (simplified, method, Some(AmountCurrency::Renminbi)));
+    test!("壹仟肆佰零玖元伍角", 1409.50f64, (simplified, method, None));
+    test!("陆仟零柒元壹角肆分", 6007.14f64, (simplified, method, None));
+    test!("壹仟陆佰捌拾元零叁角贰分", 1680.32f64, (simplified, method, None));
+    test!("壹拾万柒仟元零伍角叁分", 107000.53f64, (simplified, method, None));
+    test!("壹万陆仟肆佰零玖元零贰分", 16409.02f64, (simplified, method, None));
+    test!("叁佰贰拾伍元零肆分", 325.04f64, (simplified, method, None));
+    test!("壹拾元整", 10u8, (simplified, method, None));
+    test!("壹拾伍元整", 15i32, (simplified, method, None));
+    test!("壹仟零壹拾元整", 1010u64, (simplified, method, None));
+    test!("伍角", 0.5f32, (simplified, method, None));
+    test!("伍分", 0.049f64, (simplified, method, None));
+    test!("零元整", 0u8, (simplified, method, None));
+    test!("零元整", 0.001f64, (simplified, method, None));
+    test!("壹元整", 0.999f64, (simplified, method, None));
+    test!("零元整", -0.001f64, (simplified, method, None));
+    test!("壹亿零伍元整", 100000005u32, (simplified, method, None));
+    test!("新臺幣壹萬元整", 10000u16, (TRADITIONAL, method, Some(AmountCurrency::NewTaiwanDollar)));
+    test!("港幣參仟元正", 3000u16, (TRADITIONAL, method, Some(AmountCurrency::HongKongDollar)));
+    test!("港币叁仟元零伍分", 3000.05f64, (simplified, method, Some(AmountCurrency::HongKongDollar)));
+    test!("人民幣玖拾玖萬玖仟玖佰玖拾玖元玖角玖分", &"999999.994".parse::<Decimal>().unwrap(), (TRADITIONAL, method, Some(AmountCurrency::Renminbi)));
+    test!("壹佰万元整", &"999999.995".parse::<Decimal>().unwrap(), (simplified, method, None));
+
+    assert_eq!(Err(NumberToChineseError::UnsupportedNumberScript), 1u8.to_financial_amount(NumberScript::Japanese(ScriptStyle::Upper), method, None));
+    assert_eq!(Err(NumberToChineseError::Overflow), 1e16f64.to_financial_amount(simplified, ChineseCountMethod::Low, None));
+    assert_eq!(Err(NumberToChineseError::Underflow), f64::NEG_INFINITY.to_financial_amount(simplified, method, None));
+    assert_eq!(Err(NumberToChineseError::Underflow), (-100i16).to_financial_amount(simplified, method, Some(AmountCurrency::Renminbi)));
+    assert_eq!(Err(NumberToChineseError::Underflow), (-0.005f64).to_financial_amount(simplified, method, None));
+    assert_eq!(Err(NumberToChineseError::Underflow), (-3i8).to_financial_amount(NumberScript::Korean(ScriptStyle::Upper), method, None));
+}
+
+#[test]
+fn ordinal() {
+    use conjak_numbers::{NumberToOrdinal, OrdinalStyle};
+
+    let method = ChineseCountMethod::TenThousand;
+
+    test_method!(to_ordinal);
+
+    test!("第一", 1u8, (SIMPLIFIED, method, OrdinalStyle::Prefix));
+    test!("第十", 10i32, (SIMPLIFIED, method, OrdinalStyle::Prefix));
+    test!("第一百零一", 101u64, (SIMPLIFIED, method, OrdinalStyle::Prefix));
+    test!("第零", 0u8, (SIMPLIFIED, method, OrdinalStyle::Prefix));
+    test!("第壹佰", 100u16, (NumberScript::TraditionalChinese(ScriptStyle::Upper), method, OrdinalStyle::Prefix));
+    test!("第二十", 20u8, (JAPANESE, method, OrdinalStyle::Prefix));
+    test!("一番目", 1u8, (JAPANESE, method, OrdinalStyle::Banme));
+    test!("十五番", 15usize, (JAPANESE, method, OrdinalStyle::Ban));
+    test!("제일", 1u8, (KOREAN, method, OrdinalStyle::Prefix));
+    test!("제이십", 20u8, (KOREAN, method, OrdinalStyle::Prefix));
+
+    test!("첫째", 1u8, (KOREAN, method, OrdinalStyle::NativeKorean));
+    test!("둘째", 2u8, (KOREAN, method, OrdinalStyle::NativeKorean));
+    test!("셋째", 3u8, (KOREAN, method, OrdinalStyle::NativeKorean));
+    test!("넷째", 4u8, (KOREAN, method, OrdinalStyle::NativeKorean));
+    test!("다섯째", 5u8, (KOREAN, method, OrdinalStyle::NativeKorean));
+    test!("열째", 10u8, (KOREAN, method, OrdinalStyle::NativeKorean));
+    test!("열한째", 11u8, (KOREAN, method, OrdinalStyle::NativeKorean));
+    test!("열두째", 12u8, (KOREAN, method, OrdinalStyle::NativeKorean));
+    test!("열셋째", 13u8, (KOREAN, method, OrdinalStyle::NativeKorean));
+    test!("스무째", 20u8, (KOREAN, method, OrdinalStyle::NativeKorean));
+    test!("스물한째", 21u8, (KOREAN, method, OrdinalStyle::NativeKorean));
+    test!("서른넷째", 34u8, (KOREAN, method, OrdinalStyle::NativeKorean));
+    test!("아흔아홉째", 99i64, (KOREAN, method, OrdinalStyle::NativeKorean));
+
+    assert_eq!(Err(NumberToChineseError::NativeKoreanOutOfRange), 100u8.to_ordinal(KOREAN, method, OrdinalStyle::NativeKorean));
+    assert_eq!(Err(NumberToChineseError::NativeKoreanOutOfRange), 0u8.to_ordinal(KOREAN, method, OrdinalStyle::NativeKorean));
+    assert_eq!(Err(NumberToChineseError::Underflow), (-1i8).to_ordinal(SIMPLIFIED, method, OrdinalStyle::Prefix));
+    assert_eq!(Err(NumberToChineseError::UnsupportedNumberScript), 1u8.to_ordinal(SIMPLIFIED, method, OrdinalStyle::Banme));
+    assert_eq!(Err(NumberToChineseError::UnsupportedNumberScript), 1u8.to_ordinal(JAPANESE, method, OrdinalStyle::NativeKorean));
+}
+
+#[test]
+fn native_korean() {
+    use conjak_numbers::{NativeKoreanForm, NumberToNativeKorean};
+
+    test_method!(to_native_korean);
+
+    test!("하나", 1u8, (NativeKoreanForm::Cardinal));
+    test!("둘", 2u8, (NativeKoreanForm::Cardinal));
+    test!("열", 10u8, (NativeKoreanForm::Cardinal));
+    test!("열하나", 11u8, (NativeKoreanForm::Cardinal));
+    test!("스물", 20u8, (NativeKoreanForm::Cardinal));
+    test!("스물다섯", 25i64, (NativeKoreanForm::Cardinal));
+    test!("서른셋", 33u16, (NativeKoreanForm::Cardinal));
+    test!("아흔아홉", 99u8, (NativeKoreanForm::Cardinal));
+
+    test!("한", 1u8, (NativeKoreanForm::Attributive));
+    test!("두", 2u8, (NativeKoreanForm::Attributive));
+    test!("세", 3u8, (NativeKoreanForm::Attributive));
+    test!("네", 4u8, (NativeKoreanForm::Attributive));
+    test!("다섯", 5u8, (NativeKoreanForm::Attributive));
+    test!("열한", 11u8, (NativeKoreanForm::Attributive));
+    test!("스무", 20u8, (NativeKoreanForm::Attributive));
+    test!("스물두", 22u8, (NativeKoreanForm::Attributive));
+    test!("마흔네", 44usize, (NativeKoreanForm::Attributive));
+
+    assert_eq!(Err(NumberToChineseError::NativeKoreanOutOfRange), 0u8.to_native_korean(NativeKoreanForm::Cardinal));
+    assert_eq!(Err(NumberToChineseError::NativeKoreanOutOfRange), 100u8.to_native_korean(NativeKoreanForm::Attributive));
+    assert_eq!(Err(NumberToChineseError::NativeKoreanOutOfRange), (-1i32).to_native_korean(NativeKoreanForm::Cardinal));
+    assert_eq!(Err(NumberToChineseError::NativeKoreanOutOfRange), 1000u32.to_native_korean(NativeKoreanForm::Cardinal));
+}
+
+#[test]
+fn japanese_kana() {
+    use conjak_numbers::{Decimal, KanaFormat, KanaScript, NumberToJapaneseKana, TenSokuon};
+
+    let method = ChineseCountMethod::TenThousand;
+
+    test_method!(to_japanese_kana);
+
+    test!("ぜろ", 0u8, (method, KanaFormat::default()));
+    test!("いち", 1u8, (method, KanaFormat::default()));
+    test!("よん", 4u8, (method, KanaFormat::default()));
+    test!("なな", 7u8, (method, KanaFormat::default()));
+    test!("きゅう", 9u8, (method, KanaFormat::default()));
+    test!("じゅう", 10u8, (method, KanaFormat::default()));
+    test!("にじゅういち", 21u8, (method, KanaFormat::default()));
+    test!("ひゃく", 100u8, (method, KanaFormat::default()));
+    test!("さんびゃく", 300u16, (method, KanaFormat::default()));
+    test!("ろっぴゃく", 600u16, (method, KanaFormat::default()));
+    test!("はっぴゃく", 800u16, (method, KanaFormat::default()));
+    test!("せん", 1000u16, (method, KanaFormat::default()));
+    test!("さんぜん", 3000u16, (method, KanaFormat::default()));
+    test!("はっせん", 8000u16, (method, KanaFormat::default()));
+    test!("いちまんにせん", 1_2000u32, (method, KanaFormat::default()));
+    test!("ひゃくまん", 100_0000u32, (method, KanaFormat::default()));
+    test!("いっせんまん", 1000_0000u32, (method, KanaFormat::default()));
+    test!("せんごひゃくまん", 1500_0000u32, (method, KanaFormat::default()));
+    test!("いちおくいち", 1_0000_0001u32, (method, KanaFormat::default()));
+    test!("じゅうおく", 10_0000_0000u64, (method, KanaFormat::default()));
+    test!("いっちょう", 1_0000_0000_0000u64, (method, KanaFormat::default()));
+    test!("はっちょう", 8_0000_0000_0000u64, (method, KanaFormat::default()));
+    test!("じゅっちょう", 10_0000_0000_0000u64, (method, KanaFormat::default()));
+    test!("ひゃくちょう", 100_0000_0000_0000u64, (method, KanaFormat::default()));
+    test!("いっけい", 1_0000_0000_0000_0000u64, (method, KanaFormat::default()));
+    test!("ろっけい", 6_0000_0000_0000_0000u64, (method, KanaFormat::default()));
+    test!("ひゃっけい", 100_0000_0000_0000_0000u128, (method, KanaFormat::default()));
+    test!("さんびゃっけい", 300_0000_0000_0000_0000u128, (method, KanaFormat::default()));
+    test!("マイナスにじゅう", -20i8, (method, KanaFormat::default()));
+    test!("さんてんにご", 3.25f64, (method, KanaFormat::default()));
+    test!("ぜろてんご", &"0.5".parse::<Decimal>().unwrap(), (method, KanaFormat::default()));
+    test!("さんてんぜろご", 3.05f64, (method, KanaFormat::default()));
+    test!("ぜろてんぜろぜろご", &"0.005".parse::<Decimal>().unwrap(), (method, KanaFormat::default()));
+    test!("マイナスいちまんてんいち", &"-10000.1".parse::<Decimal>().unwrap(), (method, KanaFormat::default()));
+
+    test!("いっちょうさんおくにじゅう", 130_0020u32, (ChineseCountMethod::Low, KanaFormat::default()));
+    test!("いちまんおく", 1_0000_0000_0000u64, (ChineseCountMethod::Middle, KanaFormat::default()));
+    test!("いっせんまんおくにじゅう", 1000_0000_0000_0020u64, (ChineseCountMethod::Middle, KanaFormat::default()));
+    test!("いっちょう", 1_0000_0000_0000_0000u64, (ChineseCountMethod::Middle, KanaFormat::default()));
+    test!("いちまんおく", 1_0000_0000_0000u64, (ChineseCountMethod::High, KanaFormat::default()));
+    test!("いっちょう", 1_0000_0000_0000_0000u64, (ChineseCountMethod::High, KanaFormat::default()));
+
+    assert_eq!("ジッチョウ", 10_0000_0000_0000u64.to_japanese_kana(method, KanaFormat::new(KanaScript::Katakana, TenSokuon::Ji)).unwrap());
+    assert_eq!("じっけい", 10_0000_0000_0000_0000u64.to_japanese_kana(method, KanaFormat::new(KanaScript::Hiragana, TenSokuon::Ji)).unwrap());
+    assert_eq!("マイナスサンビャクロクジュウゴ", (-365i16).to_japanese_kana(method, KanaFormat::new(KanaScript::Katakana, TenSokuon::Ju)).unwrap());
+
+    assert_eq!(Err(NumberToChineseError::Overflow), f64::INFINITY.to_japanese_kana(method, KanaFormat::default()));
+    assert_eq!(Err(NumberToChineseError::Overflow), 1_0000_0000_0000_0000u64.to_japanese_kana(ChineseCountMethod::Low, KanaFormat::default()));
+}
+
+#[test]
+fn romanization() {
+    use conjak_numbers::{Decimal, NumberToRomanization, RomanizationSystem};
+
+    let traditional = NumberScript::TraditionalChinese(ScriptStyle::Lower { circle_as_zero: true });
+    let method = ChineseCountMethod::TenThousand;
+
+    test_method!(to_romanization);
+
+    test!("yī", 1u8, (SIMPLIFIED, method, RomanizationSystem::PinyinToneMark));
+    test!("shí", 10u8, (SIMPLIFIED, method, RomanizationSystem::PinyinToneMark));
+    test!("shí yī", 11u8, (SIMPLIFIED, method, RomanizationSystem::PinyinToneMark));
+    test!("yì bǎi èr shí sān", 123u8, (SIMPLIFIED, method, RomanizationSystem::PinyinToneMark));
+    test!("yì qiān líng yī", 1001u16, (SIMPLIFIED, method, RomanizationSystem::PinyinToneMark));
+    test!("yí wàn", 10000u16, (SIMPLIFIED, method, RomanizationSystem::PinyinToneMark));
+    test!("shí yī wàn", 110000u32, (SIMPLIFIED, method, RomanizationSystem::PinyinToneMark));
+    test!("yí yì", 100000000u32, (SIMPLIFIED, method, RomanizationSystem::PinyinToneMark));
+    test!("jiǔ qiān liù bǎi wǔ shí sì", 9654u16, (traditional, method, RomanizationSystem::PinyinToneMark));
+    test!("fù sān diǎn yī sì yī", &"-3.141".parse::<Decimal>().unwrap(), (SIMPLIFIED, method, RomanizationSystem::PinyinToneMark));
+    test!("yì jiǎo èr fēn", 0.12f64, (SIMPLIFIED, method, RomanizationSystem::PinyinToneMark));
+    test!("yi4 bai3 ling2 yi1", 101u8, (SIMPLIFIED, method, RomanizationSystem::PinyinToneNumber));
+    test!("yi2 wan4 yi4 qian1", 11000u16, (SIMPLIFIED, method, RomanizationSystem::PinyinToneNumber));
+    test!("er4 shi2 jing1 ling2 wu3 zhao4", 20_0005_0000_0000_0000u64, (traditional, method, RomanizationSystem::PinyinToneNumber));
+    test!("jat1 maan6 ling4 jat1", 10001u16, (traditional, method, RomanizationSystem::Jyutping));
+    test!("fu6 ng5 dim2 ling4 ng5", &"-5.05".parse::<Decimal>().unwrap(), (traditional, method, RomanizationSystem::Jyutping));
+    test!("saam1 gok3 ng5 fan1", 0.35f32, (traditional, method, RomanizationSystem::Jyutping));
+    test!("yì bǎi", 100u8, (NumberScript::SimplifiedChinese(ScriptStyle::Upper), method, RomanizationSystem::PinyinToneMark));
+    test!("yí wàn líng èr shí", 10020u16, (NumberScript::TraditionalChinese(ScriptStyle::Upper), method, RomanizationSystem::PinyinToneMark));
+    test!("fù yì jiǎo", -0.1f64, (NumberScript::TraditionalChinese(ScriptStyle::Upper), method, RomanizationSystem::PinyinToneMark));
+    test!("ling4 dim2 ling4 jat1", &"0.01".parse::<Decimal>().unwrap(), (traditional, method, RomanizationSystem::Jyutping));
+
+    assert_eq!(Err(NumberToChineseError::UnsupportedNumberScript), 1u8.to_romanization(NumberScript::Japanese(ScriptStyle::Upper), method, RomanizationSystem::Jyutping));
+}
+
+#[test]
+fn ratio() {
+    use conjak_numbers::{fraction_to_chinese, fraction_to_mixed, Decimal, DigitWidth, NumberToRatio, RatioStyle};
+
+    let method = ChineseCountMethod::TenThousand;
+
+    test_method!(to_ratio);
+
+    test!("百分之二十五點五", 0.255f64, (TRADITIONAL, method, RatioStyle::Percent));
+    test!("百分之五", 0.05f32, (SIMPLIFIED, method, RatioStyle::Percent));
+    test!("百分之一百", 1u8, (SIMPLIFIED, method, RatioStyle::Percent));
+    test!("百分之零", 0u8, (SIMPLIFIED, method, RatioStyle::Percent));
+    test!("百分之零点五", &"0.005".parse::<Decimal>().unwrap(), (SIMPLIFIED, method, RatioStyle::Percent));
+    test!("负百分之三", -0.03f64, (SIMPLIFIED, method, RatioStyle::Percent));
+    test!("千分之三", 0.003f64, (SIMPLIFIED, method, RatioStyle::Permille));
+    test!("千分之一點五", 0.0015f64, (TRADITIONAL, method, RatioStyle::Permille));
+    test!("백분의 이십오점오", 0.255f64, (KOREAN, method, RatioStyle::Percent));
+    test!("百分の十二", 0.12f64, (JAPANESE, method, RatioStyle::Percent));
+
+    test!("二割五分", 0.25f64, (JAPANESE, method, RatioStyle::Wari));
+    test!("二割五分五厘", 0.255f64, (JAPANESE, method, RatioStyle::Wari));
+    test!("三割", 0.3f64, (JAPANESE, method, RatioStyle::Wari));
+    test!("十割", 1u8, (JAPANESE, method, RatioStyle::Wari));
+    test!("五分", 0.05f64, (JAPANESE, method, RatioStyle::Wari));
+    test!("三割三分三厘三毛", &"0.3333".parse::<Decimal>().unwrap(), (JAPANESE, method, RatioStyle::Wari));
+    test!("零分", 0u8, (JAPANESE, method, RatioStyle::Wari));
+    test!("五厘", 0.005f64, (JAPANESE, method, RatioStyle::Wari));
+    test!("三分三厘", &"0.033".parse::<Decimal>().unwrap(), (JAPANESE, method, RatioStyle::Wari));
+    test!("負五分", -0.05f64, (JAPANESE, method, RatioStyle::Wari));
+    test!("一割", 0.1f64, (JAPANESE, method, RatioStyle::Wari));
+
+    assert_eq!(Err(NumberToChineseError::UnsupportedNumberScript), 0.25f64.to_ratio(SIMPLIFIED, method, RatioStyle::Wari));
+
+    assert_eq!("三分之一", fraction_to_chinese(SIMPLIFIED, method, 1, 3).unwrap());
+    assert_eq!("负四分之三", fraction_to_chinese(SIMPLIFIED, method, -3, 4).unwrap());
+    assert_eq!("一百分之九十九", fraction_to_chinese(TRADITIONAL, method, 99, 100).unwrap());
+    assert_eq!("三分の一", fraction_to_chinese(JAPANESE, method, 1, 3).unwrap());
+    assert_eq!("삼분의 일", fraction_to_chinese(KOREAN, method, 1, 3).unwrap());
+    assert_eq!(Err(NumberToChineseError::ZeroDenominator), fraction_to_chinese(SIMPLIFIED, method, 1, 0));
+
+    assert_eq!("3分の1", fraction_to_mixed(JAPANESE, DigitWidth::Half, 1, 3).unwrap());
+    assert_eq!("100分の15", fraction_to_mixed(JAPANESE, DigitWidth::Half, 15, 100).unwrap());
+    assert_eq!("-4分之3", fraction_to_mixed(SIMPLIFIED, DigitWidth::Half, -3, 4).unwrap());
+    assert_eq!("３분의 １", fraction_to_mixed(KOREAN, DigitWidth::Full, 1, 3).unwrap());
+    assert_eq!(Err(NumberToChineseError::ZeroDenominator), fraction_to_mixed(JAPANESE, DigitWidth::Half, 1, 0));
+}
+
+#[test]
+fn compact() {
+    use conjak_numbers::{CompactDigits, CompactFormat, Decimal, NumberToCompact, RoundingMode};
+
+
+    test_method!(to_compact);
+
+    test!("12.3万", 123456u32, (SIMPLIFIED, ChineseCountMethod::TenThousand, CompactFormat::default()));
+    test!("3.4억", 340000000u32, (KOREAN, ChineseCountMethod::TenThousand, CompactFormat::default()));
+    test!("5兆", 5_0000_0000_0000u64, (JAPANESE, ChineseCountMethod::TenThousand, CompactFormat::default()));
+    test!("1234", 1234u16, (SIMPLIFIED, ChineseCountMethod::TenThousand, CompactFormat::default()));
+    test!("12.3", 12.345f64, (SIMPLIFIED, ChineseCountMethod::TenThousand, CompactFormat::default()));
+    test!("1万", 10000u16, (SIMPLIFIED, ChineseCountMethod::TenThousand, CompactFormat::default()));
+    test!("1万", 9999.7f64, (SIMPLIFIED, ChineseCountMethod::TenThousand, CompactFormat::default()));
+    test!("1亿", 9999_9000u32, (SIMPLIFIED, ChineseCountMethod::TenThousand, CompactFormat::default()));
+    test!("-12.3萬", -123456i64, (TRADITIONAL, ChineseCountMethod::TenThousand, CompactFormat::default()));
+    test!("3400억", 3400_0000_0000u64, (KOREAN, ChineseCountMethod::TenThousand, CompactFormat::default()));
+    test!("1.23兆", 1_2345_6789_0123u64, (JAPANESE, ChineseCountMethod::TenThousand, CompactFormat::default()));
+    test!("1.2346兆", 1_2345_6789_0123u64, (JAPANESE, ChineseCountMethod::TenThousand, CompactFormat::new(CompactDigits::Arabic, 5, RoundingMode::HalfUp)));
+    test!("1万", 12345u16, (SIMPLIFIED, ChineseCountMethod::TenThousand, CompactFormat::new(CompactDigits::Arabic, 1, RoundingMode::HalfUp)));
+    test!("十二點三萬", 123456u32, (TRADITIONAL, ChineseCountMethod::TenThousand, CompactFormat::new(CompactDigits::Chinese, 3, RoundingMode::HalfUp)));
+    test!("負一點五億", -1_5000_0000i64, (TRADITIONAL, ChineseCountMethod::TenThousand, CompactFormat::new(CompactDigits::Chinese, 2, RoundingMode::HalfUp)));
+
+    test!("1.23億", 123456u32, (TRADITIONAL, ChineseCountMethod::Low, CompactFormat::default()));
+    test!("1.23兆", 1234567u32, (TRADITIONAL, ChineseCountMethod::Low, CompactFormat::default()));
+    test!("1萬億", 1_0000_0000_0000u64, (TRADITIONAL, ChineseCountMethod::Middle, CompactFormat::default()));
+    test!("1.23萬億", 1_2345_6789_0123u64, (TRADITIONAL, ChineseCountMethod::Middle, CompactFormat::default()));
+    test!("1兆", 9999_9999_9999_9999u64, (TRADITIONAL, ChineseCountMethod::Middle, CompactFormat::default()));
+    test!("1萬兆", 1_0000_0000_0000_0000_0000u128, (TRADITIONAL, ChineseCountMethod::Middle, CompactFormat::default()));
+    test!("1億兆", 1_0000_0000_0000_0000_0000_0000u128, (TRADITIONAL, ChineseCountMethod::High, CompactFormat::default()));
+    test!("一點二三萬億", 1_2345_6789_0123u64, (TRADITIONAL, ChineseCountMethod::Middle, CompactFormat::new(CompactDigits::Chinese, 3, RoundingMode::HalfUp)));
+    test!("1.23载", 123_4567_8901_2345u64, (SIMPLIFIED, ChineseCountMethod::Low, CompactFormat::default()));
+    test!("1兆", 1_0000_0000_0000_0000u64, (TRADITIONAL, ChineseCountMethod::Middle, CompactFormat::default()));
+    test!("1.23兆", 1_2345_6789_0123_4567u64, (TRADITIONAL, ChineseCountMethod::High, CompactFormat::default()));
+    test!("1.23京", &"12345678901234567".parse::<Decimal>().unwrap(), (TRADITIONAL, ChineseCountMethod::TenThousand, CompactFormat::default()));
+}
+
+#[test]
+fn mixed() {
+    use conjak_numbers::{Decimal, DigitWidth, MixedFormat, NumberToMixed};
+
+
+    test_method!(to_mixed);
+
+    test!("0", 0u8, (SIMPLIFIED, MixedFormat::default()));
+    test!("1234", 1234u16, (SIMPLIFIED, MixedFormat::default()));
+    test!("1亿2345万6789", 123456789u32, (SIMPLIFIED, MixedFormat::default()));
+    test!("1億2345萬6789", 123456789u32, (TRADITIONAL, MixedFormat::default()));
+    test!("1億2345万6789", 123456789u32, (JAPANESE, MixedFormat::default()));
+    test!("12억 3456만 7898", 1234567898u32, (KOREAN, MixedFormat::default()));
+    test!("1亿5", 100000005u32, (SIMPLIFIED, MixedFormat::default()));
+    test!("3兆5万", 3_0000_0005_0000u64, (JAPANESE, MixedFormat::default()));
+    test!("-2万300", -20300i32, (SIMPLIFIED, MixedFormat::default()));
+    test!("1万2345.67", 12345.67f64, (SIMPLIFIED, MixedFormat::default()));
+    test!("1萬0.5", &"10000.5".parse::<Decimal>().unwrap(), (TRADITIONAL, MixedFormat::default()));
+
+    test!("1亿0万5", 100000005u32, (SIMPLIFIED, MixedFormat::new(DigitWidth::Half, false)));
+    test!("１億２３４５万６７８９", 123456789u32, (JAPANESE, MixedFormat::new(DigitWidth::Full, true)));
+    test!("－１万２３４５．６", -12345.6f64, (JAPANESE, MixedFormat::new(DigitWidth::Full, true)));
+    test!("3억 0만 7", 300000007u32, (KOREAN, MixedFormat::new(DigitWidth::Half, false)));
+    test!("2만 0.25", 20000.25f64, (KOREAN, MixedFormat::default()));
+}
+
+#[test]
+fn digits() {
+    use conjak_numbers::{Decimal, DigitFormat, DigitGrouping, DigitWidth, NumberToDigits};
+
+
+    test_method!(to_digits);
+
+    test!("0", 0u8, (SIMPLIFIED, DigitFormat::default()));
+    test!("999", 999u16, (SIMPLIFIED, DigitFormat::default()));
+    test!("1,000", 1000u16, (SIMPLIFIED, DigitFormat::default()));
+    test!("123,456,789", 123456789u32, (SIMPLIFIED, DigitFormat::default()));
+    test!("-1,234.5", -1234.5f64, (SIMPLIFIED, DigitFormat::default()));
+    test!("0.25", 0.25f64, (SIMPLIFIED, DigitFormat::default()));
+    test!("123,456,789,012,345,678,901,234,567,890.125", &"123456789012345678901234567890.125".parse::<Decimal>().unwrap(), (SIMPLIFIED, DigitFormat::default()));
+
+    test!("1,2345,6789", 123456789u32, (SIMPLIFIED, DigitFormat::new(DigitWidth::Half, DigitGrouping::TenThousand, None, None)));
+    test!("1234", 1234u16, (SIMPLIFIED, DigitFormat::new(DigitWidth::Half, DigitGrouping::TenThousand, None, None)));
+    test!("12345678", 12345678u32, (SIMPLIFIED, DigitFormat::new(DigitWidth::Half, DigitGrouping::None, None, None)));
+    test!("１２３，４５６", 123456u32, (JAPANESE, DigitFormat::new(DigitWidth::Full, DigitGrouping::Thousand, None, None)));
+    test!("－１，２３４５．６７", -12345.67f64, (JAPANESE, DigitFormat::new(DigitWidth::Full, DigitGrouping::TenThousand, None, None)));
+    test!("１２３,４５６.５", 123456.5f64, (KOREAN, DigitFormat::new(DigitWidth::Full, DigitGrouping::Thousand, None, None)));
+    test!("1.234.567,5", 1234567.5f64, (VIETNAMESE, DigitFormat::default()));
+    test!("１.２３４,５", 1234.5f64, (VIETNAMESE, DigitFormat::new(DigitWidth::Full, DigitGrouping::Thousand, None, None)));
+    test!("1,234.5", 1234.5f64, (VIETNAMESE, DigitFormat::new(DigitWidth::Half, DigitGrouping::Thousand, Some(','), Some('.'))));
+
+    test!("1 234 567,5", 1234567.5f64, (SIMPLIFIED, DigitFormat::new(DigitWidth::Half, DigitGrouping::Thousand, Some(' '), Some(','))));
+    test!("1'2345", 12345u16, (JAPANESE, DigitFormat::new(DigitWidth::Half, DigitGrouping::TenThousand, Some('\''), None)));
+}
+
+#[test]
+fn suzhou() {
+    use conjak_numbers::{Decimal, NumberToSuzhou};
+
+    let traditional = NumberScript::TraditionalChinese(ScriptStyle::Upper);
+
+    macro_rules! test {
+        ($digits:expr, $magnitude:expr, $script:expr, $value:expr) => {
+            let numeral = $value.to_suzhou($script).unwrap();
+
+            assert_eq!($digits, numeral.digits);
+            assert_eq!($magnitude, numeral.magnitude);
+        };
+    }
+
+    test!("〇", "", SIMPLIFIED, 0u8);
+    test!("〨", "", SIMPLIFIED, 8u8);
+    test!("〡二〣", "百", SIMPLIFIED, 123u8);
+    test!("〤〥", "千", SIMPLIFIED, 4500u16);
+    test!("〤〇〢", "百", SIMPLIFIED, 402u16);
+    test!("〡〇〇〢", "千", SIMPLIFIED, 1002u16);
+    test!("〡一", "十", SIMPLIFIED, 11u8);
+    test!("〡一〡一", "千", SIMPLIFIED, 1111u16);
+    test!("〢三", "百", SIMPLIFIED, 230u16);
+    test!("〣〤〡", "百", SIMPLIFIED, 341u16);
+    test!("〡〥", "十万", SIMPLIFIED, 150000u32);
+    test!("〡〥", "十萬", traditional, 150000u32);
+    test!("〣", "亿", SIMPLIFIED, 3_0000_0000u32);
+    test!("〡二〥", "十", SIMPLIFIED, 12.5f64);
+    test!("〣〥", "分", SIMPLIFIED, 0.35f64);
+    test!("〥", "釐", traditional, &"0.05".parse::<Decimal>().unwrap());
+    test!("〡", "千極", traditional, &format!("1{}", "0".repeat(51)).parse::<Decimal>().unwrap());
+
+    assert_eq!(Err(NumberToChineseError::Underflow), (-1i8).to_suzhou(SIMPLIFIED));
+    assert_eq!(
+        Err(NumberToChineseError::Underflow),
+        (&format!("0.{}5", "0".repeat(256)).parse::<Decimal>().unwrap()).to_suzhou(SIMPLIFIED)
+    );
+    assert_eq!(
+        Err(NumberToChineseError::Overflow),
+        (&format!("1{}", "0".repeat(1024)).parse::<Decimal>().unwrap()).to_suzhou(SIMPLIFIED)
+    );
+    assert_eq!(
+        Err(NumberToChineseError::Overflow),
+        (&format!("1{}", "0".repeat(52)).parse::<Decimal>().unwrap()).to_suzhou(SIMPLIFIED)
+    );
+    assert_eq!(
+        Err(NumberToChineseError::UnsupportedNumberScript),
+        1u8.to_suzhou(NumberScript::Japanese(ScriptStyle::Lower { circle_as_zero: false }))
+    );
+}
+
+#[test]
+fn counting_rods() {
+    use conjak_numbers::{Decimal, NumberToCountingRods};
+
+
+    assert_eq!("〇", 0u8.to_counting_rods(SIMPLIFIED).unwrap());
+    assert_eq!("𝍦", 7u8.to_counting_rods(SIMPLIFIED).unwrap());
+    assert_eq!("𝍩〇", 10u8.to_counting_rods(SIMPLIFIED).unwrap());
+    assert_eq!("𝍱𝍨", 99u8.to_counting_rods(SIMPLIFIED).unwrap());
+    assert_eq!("𝍠𝍪𝍢", 123u8.to_counting_rods(SIMPLIFIED).unwrap());
+    assert_eq!("𝍩𝍡𝍫𝍣", 1234u16.to_counting_rods(SIMPLIFIED).unwrap());
+    assert_eq!("𝍥〇𝍡", 602u16.to_counting_rods(SIMPLIFIED).unwrap());
+    assert_eq!("负𝍦", (-7i8).to_counting_rods(SIMPLIFIED).unwrap());
+    assert_eq!("負𝍡點𝍭", (-2.5f64).to_counting_rods(TRADITIONAL).unwrap());
+    assert_eq!("〇点〇𝍤", "0.05".parse::<Decimal>().unwrap().to_counting_rods(SIMPLIFIED).unwrap());
+}
+
+#[test]
+fn vietnamese() {
+    use conjak_numbers::{NumberToQuocNgu, VietnameseDialect};
+
+    let upper = NumberScript::Vietnamese(ScriptStyle::Upper);
+
+    assert_eq!("一百二十三", 123u8.to_chinese(VIETNAMESE, ChineseCountMethod::TenThousand).unwrap());
+    assert_eq!("一萬零五", 10005u16.to_chinese(VIETNAMESE, ChineseCountMethod::TenThousand).unwrap());
+    assert_eq!("三億", 3_0000_0000u32.to_chinese(VIETNAMESE, ChineseCountMethod::TenThousand).unwrap());
+    assert_eq!("負三五角", (-3.5f64).to_chinese(VIETNAMESE, ChineseCountMethod::TenThousand).unwrap());
+    assert_eq!("壹佰貳拾參", 123u8.to_chinese(upper, ChineseCountMethod::TenThousand).unwrap());
+
+    test_method!(to_quoc_ngu);
+
+    test!("không", 0u8, (VietnameseDialect::Northern));
+    test!("năm", 5u8, (VietnameseDialect::Northern));
+    test!("mười", 10u8, (VietnameseDialect::Northern));
+    test!("mười một", 11u8, (VietnameseDialect::Northern));
+    test!("mười bốn", 14u8, (VietnameseDialect::Northern));
+    test!("mười lăm", 15u8, (VietnameseDialect::Northern));
+    test!("hai mươi", 20u8, (VietnameseDialect::Northern));
+    test!("hai mươi mốt", 21u8, (VietnameseDialect::Northern));
+    test!("hai mươi tư", 24u8, (VietnameseDialect::Northern));
+    test!("hai mươi lăm", 25u8, (VietnameseDialect::Northern));
+    test!("chín mươi chín", 99u8, (VietnameseDialect::Northern));
+    test!("một trăm", 100u8, (VietnameseDialect::Northern));
+    test!("một trăm linh năm", 105u8, (VietnameseDialect::Northern));
+    test!("một trăm lẻ năm", 105u8, (VietnameseDialect::Southern));
+    test!("một trăm mười", 110u8, (VietnameseDialect::Northern));
+    test!("một trăm hai mươi ba", 123u8, (VietnameseDialect::Northern));
+    test!("một nghìn", 1000u16, (VietnameseDialect::Northern));
+    test!("một ngàn", 1000u16, (VietnameseDialect::Southern));
+    test!("một nghìn không trăm linh năm", 1005u16, (VietnameseDialect::Northern));
+    test!("hai nghìn không trăm hai mươi lăm", 2025u16, (VietnameseDialect::Northern));
+    test!("một triệu năm trăm nghìn", 1_500_000u32, (VietnameseDialect::Northern));
+    test!("một triệu không trăm linh một", 1_000_001u32, (VietnameseDialect::Northern));
+    test!("một tỷ", 1_000_000_000u32, (VietnameseDialect::Northern));
+    test!("một nghìn tỷ", 1_000_000_000_000u64, (VietnameseDialect::Northern));
+    test!("một triệu không trăm linh năm tỷ", 1_000_005_000_000_000u64, (VietnameseDialect::Northern));
+    test!("một tỷ tỷ", 1_000_000_000_000_000_000u64, (VietnameseDialect::Northern));
+    test!("âm ba mươi mốt", -31i8, (VietnameseDialect::Northern));
+    test!("hai phẩy bảy năm", 2.75f32, (VietnameseDialect::Northern));
+    test!("không phẩy năm", 0.5f64, (VietnameseDialect::Southern));
+}
+
+#[test]
+fn japanese_daiji() {
+    let legal = NumberScript::Japanese(ScriptStyle::Upper);
+    let historic = NumberScript::Japanese(ScriptStyle::HistoricUpper);
+    let method = ChineseCountMethod::TenThousand;
+
+    assert_eq!("壱", 1u8.to_chinese(legal, method).unwrap());
+    assert_eq!("拾", 10u8.to_chinese(legal, method).unwrap());
+    assert_eq!("壱百弐拾参", 123u8.to_chinese(legal, method).unwrap());
+    assert_eq!("四千五百六拾七", 4567u16.to_chinese(legal, method).unwrap());
+    assert_eq!("参万", 30000u16.to_chinese(legal, method).unwrap());
+
+    assert_eq!("壱陌弐拾参", 123u8.to_chinese(historic, method).unwrap());
+    assert_eq!("肆阡伍陌陸拾漆", 4567u16.to_chinese(historic, method).unwrap());
+    assert_eq!("捌萬玖阡", 89000u32.to_chinese(historic, method).unwrap());
+    assert_eq!("参億", 3_0000_0000u32.to_chinese(historic, method).unwrap());
+    assert_eq!("玖", 9u8.to_chinese_naive(historic));
+
+    // 其他語言沒有舊式大字，與 `Upper` 相同
+    assert_eq!(
+        456u16.to_chinese(NumberScript::TraditionalChinese(ScriptStyle::Upper), method).unwrap(),
+        456u16.to_chinese(NumberScript::TraditionalChinese(ScriptStyle::HistoricUpper), method).unwrap()
+    );
+}
+
+#[test]
+fn korean_upper() {
+    use conjak_numbers::NumberToFinancialAmount;
+
+    let upper = NumberScript::Korean(ScriptStyle::Upper);
+    let method = ChineseCountMethod::TenThousand;
+
+    assert_eq!("零", 0u8.to_chinese(upper, method).unwrap());
+    assert_eq!("拾", 10u8.to_chinese(upper, method).unwrap());
+    assert_eq!("壹百貳拾參", 123u8.to_chinese(upper, method).unwrap());
+    assert_eq!("四千五百六拾七", 4567u16.to_chinese(upper, method).unwrap());
+    assert_eq!("壹百貳拾參萬", 1230000u32.to_chinese(upper, method).unwrap());
+    assert_eq!("九億八千萬", 9_8000_0000u32.to_chinese(upper, method).unwrap());
+    assert_eq!("負參點五", (-3.5f64).to_chinese_naive(upper));
+
+    assert_eq!("일금 일백이십삼만원정", 1230000u32.to_financial_amount(KOREAN, method, None).unwrap());
+    assert_eq!("일금 일십만원정", 100000u32.to_financial_amount(KOREAN, method, None).unwrap());
+    assert_eq!("일금 영원정", 0u8.to_financial_amount(KOREAN, method, None).unwrap());
+    assert_eq!("일금 일천원정", 999.5f64.to_financial_amount(KOREAN, method, None).unwrap());
+    assert_eq!("一金 壹百貳拾參萬원整", 1230000u32.to_financial_amount(upper, method, None).unwrap());
+    assert_eq!("一金 壹拾五萬원整", 150000u32.to_financial_amount(upper, method, None).unwrap());
+    assert_eq!("일금 일천오원정", 1005u32.to_financial_amount(KOREAN, method, None).unwrap());
+    assert_eq!("一金 壹千五원整", 1005u32.to_financial_amount(upper, method, None).unwrap());
+    assert_eq!("일금 일억오만원정", 100050000u32.to_financial_amount(KOREAN, method, None).unwrap());
+}
+
+#[test]
+fn spacing() {
+    use conjak_numbers::{Decimal, NumberToChineseWithSpacing, SpacingFormat};
+
+
+    test_method!(to_chinese_with_spacing);
+
+    let standard = SpacingFormat::korean();
+
+    test!("영", 0u8, (KOREAN, ChineseCountMethod::TenThousand, standard));
+    test!("십", 10u8, (KOREAN, ChineseCountMethod::TenThousand, standard));
+    test!("백", 100u8, (KOREAN, ChineseCountMethod::TenThousand, standard));
+    test!("천백십일", 1111u16, (KOREAN, ChineseCountMethod::TenThousand, standard));
+    test!("만", 10000u16, (KOREAN, ChineseCountMethod::TenThousand, standard));
+    test!("만 천", 11000u16, (KOREAN, ChineseCountMethod::TenThousand, standard));
+    test!("십만", 100000u32, (KOREAN, ChineseCountMethod::TenThousand, standard));
+    test!("천만", 10000000u32, (KOREAN, ChineseCountMethod::TenThousand, standard));
+    test!("일억", 100000000u32, (KOREAN, ChineseCountMethod::TenThousand, standard));
+    test!("일억 만", 100010000u32, (KOREAN, ChineseCountMethod::TenThousand, standard));
+    test!("천오", 1005u16, (KOREAN, ChineseCountMethod::TenThousand, standard));
+    test!("일억 오", 100000005u32, (KOREAN, ChineseCountMethod::TenThousand, standard));
+    test!("십이억 삼천사백오십육만 칠천팔백구십", 1234567890u32, (KOREAN, ChineseCountMethod::TenThousand, standard));
+    test!("부이만 삼천", -23000i32, (KOREAN, ChineseCountMethod::TenThousand, standard));
+    test!("삼만 오천점이오", 35000.25f64, (KOREAN, ChineseCountMethod::TenThousand, standard));
+
+    test!("일억이천삼백사십오만육천칠백팔십구", 123456789u32, (KOREAN, ChineseCountMethod::TenThousand, SpacingFormat::default()));
+    test!("일억 이천삼백사십오만 육천칠백팔십구", 123456789u32, (KOREAN, ChineseCountMethod::TenThousand, SpacingFormat::new(Some(' '), false, false)));
+    test!("一亿 二千三百四十五万 六千七百八十九", 123456789u32, (SIMPLIFIED, ChineseCountMethod::TenThousand, SpacingFormat::new(Some(' '), false, false)));
+    test!("一亿 零五", 100000005u32, (SIMPLIFIED, ChineseCountMethod::TenThousand, SpacingFormat::new(Some(' '), false, false)));
+    test!("千二百万", 12000000u32, (JAPANESE, ChineseCountMethod::TenThousand, SpacingFormat::new(None, true, false)));
+    test!("一億 千万", &"110000000".parse::<Decimal>().unwrap(), (JAPANESE, ChineseCountMethod::TenThousand, SpacingFormat::new(Some(' '), true, false)));
+    test!("一亿 零一万", 100010000u32, (SIMPLIFIED, ChineseCountMethod::TenThousand, SpacingFormat::new(Some(' '), true, false)));
+    test!("一亿 万", 100010000u32, (SIMPLIFIED, ChineseCountMethod::TenThousand, standard));
+    test!("一亿 零一百万", 101000000u32, (SIMPLIFIED, ChineseCountMethod::TenThousand, SpacingFormat::new(Some(' '), true, false)));
+    test!("일천영오", 1005u16, (KOREAN, ChineseCountMethod::TenThousand, SpacingFormat::default()));
+    test!("일억 영오", 100000005u32, (KOREAN, ChineseCountMethod::TenThousand, SpacingFormat::new(Some(' '), false, false)));
+    test!("천오", 1005u16, (KOREAN, ChineseCountMethod::TenThousand, SpacingFormat::new(None, true, true)));
+
+    // 其他算法不套用
+    assert_eq!(
+        "一千一百一十一",
+        1111u16.to_chinese_with_spacing(SIMPLIFIED, ChineseCountMethod::Low, standard).unwrap()
+    );
+}
+
+#[test]
+fn locale_rules() {
+    use conjak_numbers::{Decimal, LocalePreset, LocaleRules, TwoForm};
+
+    let upper = NumberScript::SimplifiedChinese(ScriptStyle::Upper);
+
+    test_method!(to_chinese_with_rules);
+
+    // 預設值與 to_chinese 相同
+    for v in [0u64, 10, 12, 110, 1000, 2000, 10000, 20000, 100010, 2_0000_0000, 1234567890123] {
+        assert_eq!(
+            v.to_chinese(TRADITIONAL, ChineseCountMethod::TenThousand).unwrap(),
+            v.to_chinese_with_rules(TRADITIONAL, ChineseCountMethod::TenThousand, LocaleRules::default(), None).unwrap()
+        );
+    }
+
+    test!("两千", 2000u16, (SIMPLIFIED, ChineseCountMethod::TenThousand, LocalePreset::Cn.into(), None));
+    test!("二百", 200u16, (SIMPLIFIED, ChineseCountMethod::TenThousand, LocalePreset::Cn.into(), None));
+    test!("两千二百二十二", 2222u16, (SIMPLIFIED, ChineseCountMethod::TenThousand, LocalePreset::Cn.into(), None));
+    test!("两万二千", 22000u16, (SIMPLIFIED, ChineseCountMethod::TenThousand, LocalePreset::Sg.into(), None));
+    test!("二十万", 200000u32, (SIMPLIFIED, ChineseCountMethod::TenThousand, LocalePreset::Cn.into(), None));
+    test!("两亿", 2_0000_0000u32, (SIMPLIFIED, ChineseCountMethod::TenThousand, LocalePreset::Cn.into(), None));
+    test!("一万二千", 12000u16, (SIMPLIFIED, ChineseCountMethod::TenThousand, LocalePreset::Cn.into(), None));
+    test!("一百一十", 110u8, (SIMPLIFIED, ChineseCountMethod::TenThousand, LocalePreset::Cn.into(), None));
+    test!("十二", 12u8, (SIMPLIFIED, ChineseCountMethod::TenThousand, LocalePreset::Cn.into(), None));
+    test!("负两千", -2000i16, (SIMPLIFIED, ChineseCountMethod::TenThousand, LocalePreset::Cn.into(), None));
+
+    test!("二千", 2000u16, (TRADITIONAL, ChineseCountMethod::TenThousand, LocalePreset::Tw.into(), None));
+    test!("兩百", 200u16, (TRADITIONAL, ChineseCountMethod::TenThousand, LocalePreset::Hk.into(), None));
+    test!("兩千", 2000u16, (TRADITIONAL, ChineseCountMethod::TenThousand, LocalePreset::Mo.into(), None));
+    test!("兩萬零二百", 20200u16, (TRADITIONAL, ChineseCountMethod::TenThousand, LocalePreset::Hk.into(), None));
+
+    test!("千", 1000u16, (JAPANESE, ChineseCountMethod::TenThousand, LocalePreset::Jp.into(), None));
+    test!("百十", 110u8, (JAPANESE, ChineseCountMethod::TenThousand, LocalePreset::Jp.into(), None));
+    test!("千百十一", 1111u16, (JAPANESE, ChineseCountMethod::TenThousand, LocalePreset::Jp.into(), None));
+    test!("一万千", 11000u16, (JAPANESE, ChineseCountMethod::TenThousand, LocalePreset::Jp.into(), None));
+    test!("二千", 2000u16, (JAPANESE, ChineseCountMethod::TenThousand, LocalePreset::Jp.into(), None));
+    test!("十億零十", 10_0000_0010u64, (JAPANESE, ChineseCountMethod::TenThousand, LocalePreset::Jp.into(), None));
+
+    test!("一十二", 12u8, (SIMPLIFIED, ChineseCountMethod::TenThousand, LocaleRules::new(true, true, true, true, TwoForm::Er), None));
+    test!("万", 10000u16, (SIMPLIFIED, ChineseCountMethod::TenThousand, LocaleRules::new(false, true, true, false, TwoForm::Er), None));
+    test!("亿零一万", 1_0001_0000u32, (SIMPLIFIED, ChineseCountMethod::TenThousand, LocaleRules::new(false, true, true, false, TwoForm::Er), None));
+
+    // 大寫不使用「兩」
+    test!("贰仟", 2000u16, (upper, ChineseCountMethod::TenThousand, LocalePreset::Cn.into(), None));
+
+    assert_eq!(
+        "两千点五",
+        "2000.5".parse::<Decimal>().unwrap().to_chinese_with_rules(SIMPLIFIED, ChineseCountMethod::TenThousand, LocalePreset::Cn.rules(), None).unwrap()
+    );
+    assert_eq!(
+        "两千五角",
+        2000.5f64.to_chinese_with_rules(SIMPLIFIED, ChineseCountMethod::TenThousand, LocalePreset::Cn.rules(), None).unwrap()
+    );
+    assert_eq!(
+        "两亿",
+        2_0000_0000u32.to_chinese_with_rules(SIMPLIFIED, ChineseCountMethod::Middle, LocalePreset::Cn.rules(), None).unwrap()
+    );
+    assert_eq!(
+        "两千",
+        2000u16.to_chinese_with_rules(SIMPLIFIED, ChineseCountMethod::Low, LocalePreset::Cn.rules(), None).unwrap()
+    );
+}
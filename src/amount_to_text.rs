@@ -0,0 +1,30 @@
+use ciborium::from_reader;
+use conjak_numbers::{
+    AmountCurrency, ChineseCountMethod, NumberScript, NumberToFinancialAmount, ScriptStyle,
+};
+use serde::Deserialize;
+
+use crate::number_to_text::NumberInput;
+
+#[derive(Deserialize)]
+struct Input {
+    value: NumberInput,
+    number_script: Option<NumberScript>,
+    count_method: Option<ChineseCountMethod>,
+    currency: Option<AmountCurrency>,
+}
+
+pub fn amount_to_text(input: &[u8]) -> Result<Vec<u8>, String> {
+    let input: Input = from_reader(input).map_err(|e| e.to_string())?;
+    let number_script = input
+        .number_script
+        .unwrap_or(NumberScript::SimplifiedChinese(ScriptStyle::Upper));
+    let count_method = input
+        .count_method
+        .unwrap_or(ChineseCountMethod::TenThousand);
+    let value = input.value.into_decimal()?;
+    let s = value
+        .to_financial_amount(number_script, count_method, input.currency)
+        .map_err(|e| e.to_string())?;
+    Ok(s.as_bytes().to_vec())
+}
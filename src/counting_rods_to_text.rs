@@ -0,0 +1,27 @@
+use ciborium::from_reader;
+use conjak_numbers::{NumberScript, NumberToCountingRods, ScriptStyle};
+use serde::Deserialize;
+
+use crate::number_to_text::NumberInput;
+
+#[derive(Deserialize)]
+struct Input {
+    value: NumberInput,
+    /// Only used for the sign and the decimal point
+    number_script: Option<NumberScript>,
+}
+
+pub fn counting_rods_to_text(input: &[u8]) -> Result<Vec<u8>, String> {
+    let input: Input = from_reader(input).map_err(|e| e.to_string())?;
+    let number_script = input
+        .number_script
+        .unwrap_or(NumberScript::TraditionalChinese(ScriptStyle::Lower {
+            circle_as_zero: false,
+        }));
+    let s = input
+        .value
+        .into_decimal()?
+        .to_counting_rods(number_script)
+        .map_err(|e| e.to_string())?;
+    Ok(s.as_bytes().to_vec())
+}
@@ -1,6 +1,13 @@
 use typst_wasm_protocol::wasm_export;
 
+mod amount_to_text;
+mod counting_rods_to_text;
 mod number_to_text;
+mod ordinal_to_text;
+mod ratio_to_text;
+mod solar_terms;
+mod suzhou_to_text;
+mod text_to_number;
 mod lunar;
 
 #[wasm_export]
@@ -8,7 +15,62 @@ pub fn number_to_text(input: &[u8]) -> Result<Vec<u8>, String> {
     number_to_text::number_to_text(input).map_err(|e| e.to_string())
 }
 
+#[wasm_export]
+pub fn amount_to_text(input: &[u8]) -> Result<Vec<u8>, String> {
+    amount_to_text::amount_to_text(input).map_err(|e| e.to_string())
+}
+
+#[wasm_export]
+pub fn ordinal_to_text(input: &[u8]) -> Result<Vec<u8>, String> {
+    ordinal_to_text::ordinal_to_text(input).map_err(|e| e.to_string())
+}
+
+#[wasm_export]
+pub fn ratio_to_text(input: &[u8]) -> Result<Vec<u8>, String> {
+    ratio_to_text::ratio_to_text(input).map_err(|e| e.to_string())
+}
+
+#[wasm_export]
+pub fn fraction_to_text(input: &[u8]) -> Result<Vec<u8>, String> {
+    ratio_to_text::fraction_to_text(input).map_err(|e| e.to_string())
+}
+
+#[wasm_export]
+pub fn suzhou_to_text(input: &[u8]) -> Result<Vec<u8>, String> {
+    suzhou_to_text::suzhou_to_text(input).map_err(|e| e.to_string())
+}
+
+#[wasm_export]
+pub fn counting_rods_to_text(input: &[u8]) -> Result<Vec<u8>, String> {
+    counting_rods_to_text::counting_rods_to_text(input).map_err(|e| e.to_string())
+}
+
+#[wasm_export]
+pub fn text_to_number(input: &[u8]) -> Result<Vec<u8>, String> {
+    text_to_number::text_to_number(input).map_err(|e| e.to_string())
+}
+
 #[wasm_export]
 pub fn solar_to_lunisolar(input: &[u8]) -> Result<Vec<u8>, String> {
     lunar::solar_to_lunisolar(input).map_err(|e| e.to_string())
 }
+
+#[wasm_export]
+pub fn lunisolar_to_solar(input: &[u8]) -> Result<Vec<u8>, String> {
+    lunar::lunisolar_to_solar(input).map_err(|e| e.to_string())
+}
+
+#[wasm_export]
+pub fn four_pillars(input: &[u8]) -> Result<Vec<u8>, String> {
+    lunar::four_pillars(input).map_err(|e| e.to_string())
+}
+
+#[wasm_export]
+pub fn solar_terms(input: &[u8]) -> Result<Vec<u8>, String> {
+    solar_terms::solar_terms(input).map_err(|e| e.to_string())
+}
+
+#[wasm_export]
+pub fn solar_term_of_date(input: &[u8]) -> Result<Vec<u8>, String> {
+    solar_terms::solar_term_of_date(input).map_err(|e| e.to_string())
+}
@@ -1,5 +1,49 @@
 #let plg = plugin("conjak.wasm")
 
+// The number script matching a language and region, in the form the plugin expects.
+#let lang-number-script(lang, region, daxie, maru-zero) = {
+  let (l, r) = (lower(lang), if region == none { "" } else { lower(region) })
+  let style = if daxie == "historic" {
+    "historic_upper"
+  } else if daxie == true {
+    "upper"
+  } else if maru-zero {
+    (lower: (circle_as_zero: true))
+  } else {
+    (lower: (circle_as_zero: false))
+  }
+  if l == "zh" {
+    if r in ("hk", "mo", "tw") {
+      (traditional_chinese: style)
+    } else {
+      (simplified_chinese: style)
+    }
+  } else if l == "ja" {
+    (japanese: style)
+  } else if l == "ko" {
+    (korean: style)
+  } else if l == "vi" {
+    (vietnamese: style)
+  } else {
+    panic("Unsupported language: " + l)
+  }
+}
+
+// The number script matching the current text language and region. Must be called in a context.
+#let text-number-script(daxie, maru-zero) = lang-number-script(text.lang, text.region, daxie, maru-zero)
+
+// The built-in rule set matching the current text language and region, or none for languages without one. Must be called in a context.
+#let text-locale-preset() = {
+  let (l, r) = (lower(text.lang), if text.region == none { "" } else { lower(text.region) })
+  if l == "zh" {
+    if r in ("cn", "sg", "tw", "hk", "mo") { r } else { "cn" }
+  } else if l == "ja" {
+    "jp"
+  } else {
+    none
+  }
+}
+
 /// Generate a string with the given value formatted with thousands separators.
 /// ```example
 /// #set text(lang: "ja", region: "jp")
@@ -7,66 +51,336 @@
 /// #conjak.format-number(1145141919810)\
 /// #conjak.format-number(1145141919810000)\
 /// #conjak.format-number(135700012255)\
+/// #conjak.format-number("123456789012345678901234567890.125")\
+/// #conjak.format-number(decimal("0.1") + decimal("0.2"))\
+/// #conjak.format-number(3.14159, fraction: (mode: "decimal_point", precision: 3))\
+/// #conjak.format-number(0.0314, fraction: (mode: "small_unit", precision: 4))\
+/// #conjak.format-number(4567, daxie: true)\
+/// #conjak.format-number(4567, daxie: "historic")\
+/// #conjak.format-number(8800, kana: "hiragana")\
+/// #conjak.format-number(1000000000000, kana: (script: "katakana", ten_sokuon: "ji"))\
+/// #text(lang: "zh", conjak.format-number(12345, romanization: "pinyin_tone_mark"))\
+/// #text(lang: "zh", region: "hk", conjak.format-number(12345, romanization: "jyutping"))\
+/// #conjak.format-number(5000000000000, compact: true)\
+/// #text(lang: "ko", conjak.format-number(340000000, compact: (significant_figures: 2)))\
+/// #conjak.format-number(123456789, mixed: true)\
+/// #conjak.format-number(100000005, mixed: (width: "full", omit_empty_groups: false))\
+/// #conjak.format-number(1234567.89, digits: true)\
+/// #conjak.format-number(123456789, digits: (width: "full", grouping: "ten_thousand"))\
+/// #text(lang: "ko", conjak.format-number(1234567890, spacing: true))\
+/// #text(lang: "ko", conjak.format-number(11000, spacing: true))\
+/// #text(lang: "zh", region: "cn", conjak.format-number(2000, rules: auto))\
+/// #text(lang: "zh", region: "hk", conjak.format-number(200, rules: auto))\
+/// #text(lang: "ja", conjak.format-number(1110, rules: auto))\
+/// #text(lang: "vi", conjak.format-number(10005))\
+/// #text(lang: "vi", conjak.format-number(105, quoc-ngu: true))\
+/// #text(lang: "vi", conjak.format-number(2025, quoc-ngu: "southern"))
 /// ```
 ///
-/// - value (int, float): The number to format
-/// - daxie (bool): Whether or not to use "大写" or "大字"
+/// - value (int, float, str, decimal): The number to format. Strings and decimals keep every digit, and their fractional part is read digit by digit
+/// - daxie (bool, str): Whether or not to use "大写" or "大字". In Japanese, `true` uses the legal daiji (壱弐参拾) and "historic" also uses the old forms found in historic documents (肆伍陸漆捌玖陌阡萬)
 /// - maru-zero (bool): Whether or not to use "〇" as zero
 /// - count-method (str): The counting method to use. Can be "high", "middle", "low", or "ten_thousand"
+/// - fraction (none, dictionary): How to read the fractional part. `none` keeps the default reading. A dictionary may set `mode` ("currency" for 角分釐, "decimal_point" for 點 followed by digits, or "small_unit" for 分釐毫絲忽微…), `precision` (digits to keep, defaults to 2) and `rounding` ("truncate", "up", "half_up" or "half_even", defaults to "half_up"). It only applies to the numeral itself, so it can be combined with `rules` but not with the other options
+/// - kana (none, str, dictionary): Write the Japanese reading in kana instead of the numeral, with sound changes such as さんびゃく or いっちょう, following `count-method`. Can be "hiragana" or "katakana", or a dictionary with `script` and `ten_sokuon` ("ju" for じゅっ, "ji" for じっ)
+/// - quoc-ngu (none, bool, str): Write the Vietnamese reading in Quốc ngữ instead of the numeral, such as một trăm linh năm. `true` or "northern" uses nghìn and linh, "southern" uses ngàn and lẻ. Only works when the text language is Vietnamese
+/// - romanization (none, str): Write the pronunciation of a Chinese numeral instead of the numeral. Can be "pinyin_tone_mark" (yì bǎi), "pinyin_tone_number" (yi4 bai3) or "jyutping" (jat1 baak3). Only works when the text language is Chinese
+/// - compact (none, bool, dictionary): Abbreviate the number with the largest unit it reaches, such as 12.3万 or 3.4억. `true` uses Arabic digits and 3 significant figures. A dictionary may set `digits` ("arabic" or "chinese"), `significant_figures` and `rounding` ("truncate", "up", "half_up" or "half_even")
+/// - mixed (none, bool, dictionary): Write Arabic digits between the myriad units, such as 1億2345万6789 or 12억 3456만 7898. `true` uses half-width digits and skips groups that are all zero. A dictionary may set `width` ("half" or "full") and `omit_empty_groups`
+/// - digits (none, bool, dictionary): Write grouped Arabic digits instead of the numeral, such as 1,234,567.89. `true` uses half-width digits grouped by three. A dictionary may set `width` ("half" or "full"), `grouping` ("thousand", "ten_thousand" or "none"), `separator` and `decimal_mark`. Vietnamese groups with . and uses , as the decimal mark. Full-width digits use ， and ． in Chinese and Japanese
+/// - spacing (none, bool, dictionary): Separate the myriad groups and drop the 一 before 十, 百, 千 and 萬, as in 십이억 삼천사백오십육만 칠천팔백구십. `true` follows the Korean standard, which puts a space after each myriad unit, writes 만 rather than 일만 and, in Korean, leaves out 영. A dictionary may set `separator` (a character, or `none`), `omit_leading_one` and `omit_zero`. The 一 after a 零 is kept, as in 一亿 零一万. Only the "ten_thousand" counting method is affected
+/// - rules (none, auto, str, dictionary): When to drop the leading 一 and write 兩, which differs between regions (两千 or 二千, 一千 or 千, 一百一十 or 百十). `none` keeps the default writing. `auto` picks the rules of the text region ("cn", "sg", "tw", "hk" or "mo", or "jp" for Japanese), which can also be given by name. A dictionary may set `one_before_leading_ten`, `one_before_inner_ten`, `one_before_hundred_and_thousand`, `one_before_myriad` and `two` ("er", "before_thousand" or "before_hundred"). 兩 is only written in lower-case Chinese. Apart from `fraction`, only one of `kana`, `quoc-ngu`, `romanization`, `compact`, `mixed`, `digits`, `spacing` and `rules` can be set, and combining them is an error
 /// -> str
 #let format-number(
   value,
   daxie: false,
   maru-zero: false,
   count-method: "ten_thousand",
+  fraction: none,
+  kana: none,
+  quoc-ngu: none,
+  romanization: none,
+  compact: none,
+  mixed: none,
+  digits: none,
+  spacing: none,
+  rules: none,
 ) = context {
-  if daxie and maru-zero {
+  if daxie != false and maru-zero {
     panic("You cannot use both 'daxie' and 'maru-zero' at the same time.")
   }
-  let (l, r) = (lower(text.lang), lower(text.region))
-  let number-script = if l == "zh" {
-    let key = if r in ("hk", "mo", "tw") {
-      "traditional_chinese"
-    } else {
-      "simplified_chinese"
-    }
-    let val = if daxie {
-      "upper"
-    } else if maru-zero {
-      (lower: (circle_as_zero: true))
-    } else {
-      (lower: (circle_as_zero: false))
-    }
-    let ret = (:)
-    ret.insert(key, val)
-    ret
-  } else if l == "ja" {
-    let val = if daxie {
-      "upper"
-    } else if maru-zero {
-      (lower: (circle_as_zero: true))
-    } else {
-      (lower: (circle_as_zero: false))
-    }
-    (japanese: val)
-  } else if l == "ko" {
-    let val = if daxie {
-      "upper"
-    } else if maru-zero {
-      (lower: (circle_as_zero: true))
-    } else {
-      (lower: (circle_as_zero: false))
-    }
-    (korean: val)
-  } else {
+  let number-script = text-number-script(daxie, maru-zero)
+  str(
+    plg.number_to_text(
+      cbor.encode((
+        value: if type(value) == decimal { str(value) } else { value },
+        number_script: number-script,
+        count_method: count-method,
+        fraction: fraction,
+        kana: if type(kana) == str { (script: kana) } else { kana },
+        quoc_ngu: if quoc-ngu == true { "northern" } else if quoc-ngu == false { none } else { quoc-ngu },
+        romanization: romanization,
+        compact: if compact == true { (:) } else if compact == false { none } else { compact },
+        mixed: if mixed == true { (:) } else if mixed == false { none } else { mixed },
+        digits: if digits == true { (:) } else if digits == false { none } else { digits },
+        spacing: if spacing == true {
+          (separator: " ", omit_leading_one: true, omit_zero: lower(text.lang) == "ko")
+        } else if spacing == false { none } else { spacing },
+        rules: if rules == auto { text-locale-preset() } else { rules },
+      )),
+    ),
+  )
+}
+
+/// Format an ordinal number such as 第一, 三番目 or 첫째. It can be used directly as a numbering function. Nested headings and lists pass the numbers of every level, and only the last one is formatted.
+/// ```example
+/// #set text(lang: "ko")
+/// #conjak.format-ordinal(3)\
+/// #conjak.format-ordinal(21, style: "native_korean")\
+/// #text(lang: "ja", conjak.format-ordinal(2, style: "banme"))\
+/// #set heading(numbering: conjak.format-ordinal.with(style: "native_korean"))
+/// ```
+///
+/// - ..nums (int): The position to format, or the numbers of every level, of which the last is formatted. Must not be negative
+/// - style (str): "prefix" for 第N (제N in Korean), "banme" for N番目 and "ban" for N番 (Japanese only), or "native_korean" for 첫째, 둘째… (Korean only, 1 to 99)
+/// - daxie (bool, str): Whether or not to use "大写" or "大字". In Japanese, `true` uses the legal daiji (壱弐参拾) and "historic" also uses the old forms found in historic documents (肆伍陸漆捌玖陌阡萬)
+/// - maru-zero (bool): Whether or not to use "〇" as zero
+/// - count-method (str): The counting method to use. Can be "high", "middle", "low", or "ten_thousand"
+/// -> str
+#let format-ordinal(
+  ..nums,
+  style: "prefix",
+  daxie: false,
+  maru-zero: false,
+  count-method: "ten_thousand",
+) = context {
+  if daxie != false and maru-zero {
+    panic("You cannot use both 'daxie' and 'maru-zero' at the same time.")
+  }
+  str(
+    plg.ordinal_to_text(
+      cbor.encode((
+        value: nums.pos().last(),
+        number_script: text-number-script(daxie, maru-zero),
+        count_method: count-method,
+        style: style,
+      )),
+    ),
+  )
+}
+
+/// Format a ratio as a percentage (百分之二十五點五), a permille (千分之三) or, in Japanese, with 割分厘 (二割五分五厘). The value is the ratio itself, so `0.255` is 25.5%.
+/// ```example
+/// #set text(lang: "zh", region: "tw")
+/// #conjak.format-ratio(0.255)\
+/// #conjak.format-ratio(0.003, style: "permille")\
+/// #text(lang: "ko", conjak.format-ratio(0.255))\
+/// #text(lang: "ja", conjak.format-ratio(0.255, style: "wari"))
+/// ```
+///
+/// - value (int, float, str, decimal): The ratio to format. Use strings or decimals for exact values
+/// - style (str): "percent", "permille" or "wari" (Japanese only)
+/// - daxie (bool, str): Whether or not to use "大写" or "大字". In Japanese, `true` uses the legal daiji (壱弐参拾) and "historic" also uses the old forms found in historic documents (肆伍陸漆捌玖陌阡萬)
+/// - maru-zero (bool): Whether or not to use "〇" as zero
+/// - count-method (str): The counting method to use. Can be "high", "middle", "low", or "ten_thousand"
+/// -> str
+#let format-ratio(
+  value,
+  style: "percent",
+  daxie: false,
+  maru-zero: false,
+  count-method: "ten_thousand",
+) = context {
+  if daxie != false and maru-zero {
+    panic("You cannot use both 'daxie' and 'maru-zero' at the same time.")
+  }
+  str(
+    plg.ratio_to_text(
+      cbor.encode((
+        value: if type(value) == decimal { str(value) } else { value },
+        number_script: text-number-script(daxie, maru-zero),
+        count_method: count-method,
+        style: style,
+      )),
+    ),
+  )
+}
+
+/// Format a fraction such as 三分之一, 三分の一 or 삼분의 일, or with Arabic digits such as 3分の1.
+/// ```example
+/// #set text(lang: "zh")
+/// #conjak.format-fraction(1, 3)\
+/// #conjak.format-fraction(-3, 4)\
+/// #text(lang: "ja", conjak.format-fraction(1, 3))\
+/// #text(lang: "ja", conjak.format-fraction(1, 3, arabic: true))\
+/// #text(lang: "ko", conjak.format-fraction(1, 3))
+/// ```
+///
+/// - numerator (int): The numerator. A negative numerator makes the whole fraction negative
+/// - denominator (int): The denominator. Must be positive
+/// - daxie (bool, str): Whether or not to use "大写" or "大字". In Japanese, `true` uses the legal daiji (壱弐参拾) and "historic" also uses the old forms found in historic documents (肆伍陸漆捌玖陌阡萬)
+/// - maru-zero (bool): Whether or not to use "〇" as zero
+/// - count-method (str): The counting method to use. Can be "high", "middle", "low", or "ten_thousand"
+/// - arabic (bool, str): Write the numerator and the denominator in Arabic digits, such as 3分の1. `true` uses half-width digits, and "full" uses full-width digits such as ３分の１
+/// -> str
+#let format-fraction(
+  numerator,
+  denominator,
+  daxie: false,
+  maru-zero: false,
+  count-method: "ten_thousand",
+  arabic: false,
+) = context {
+  if daxie != false and maru-zero {
+    panic("You cannot use both 'daxie' and 'maru-zero' at the same time.")
+  }
+  str(
+    plg.fraction_to_text(
+      cbor.encode((
+        numerator: numerator,
+        denominator: denominator,
+        number_script: text-number-script(daxie, maru-zero),
+        count_method: count-method,
+        arabic: if arabic == true { "half" } else if arabic == false { none } else { arabic },
+      )),
+    ),
+  )
+}
+
+/// Write a number in Suzhou numerals (蘇州碼子), with the digits on top and the place of the first digit below, such as 〤〇〢 over 百 for 402. Zeros between digits are written as 〇, trailing zeros are left to the place line, and 1 to 3 switch to the horizontal 一二三 after another vertical 1 to 3.
+/// ```example
+/// #set text(lang: "zh", region: "hk")
+/// #conjak.format-suzhou(402)
+/// #conjak.format-suzhou(1500, unit: "元")
+/// #conjak.format-suzhou(12.5, unit: "元")
+/// ```
+///
+/// - value (int, float, str, decimal): The number to write. Must not be negative
+/// - unit (none, str, content): A unit written after the place, such as 元 or 斤
+/// -> content
+#let format-suzhou(
+  value,
+  unit: none,
+) = context {
+  let l = lower(text.lang)
+  if l != "zh" {
+    panic("Unsupported language: " + l)
+  }
+  let numeral = cbor(
+    plg.suzhou_to_text(
+      cbor.encode((
+        value: if type(value) == decimal { str(value) } else { value },
+        number_script: text-number-script(false, false),
+      )),
+    ),
+  )
+  box(
+    stack(
+      dir: ttb,
+      spacing: 0.25em,
+      numeral.digits,
+      text(size: 0.7em, numeral.magnitude + if unit != none { unit }),
+    ),
+  )
+}
+
+/// Write a number in counting-rod numerals (算籌). Each place alternates between the vertical and the horizontal rods, starting with vertical rods in the units place, and empty places are written as 〇.
+/// ```example
+/// #set text(lang: "zh", region: "tw")
+/// #conjak.format-counting-rods(1234)\
+/// #conjak.format-counting-rods(602)\
+/// #conjak.format-counting-rods(-2.5)
+/// ```
+///
+/// - value (int, float, str, decimal): The number to write. The sign and the decimal point follow the text language
+/// -> str
+#let format-counting-rods(value) = context {
+  str(
+    plg.counting_rods_to_text(
+      cbor.encode((
+        value: if type(value) == decimal { str(value) } else { value },
+        number_script: text-number-script(false, false),
+      )),
+    ),
+  )
+}
+
+/// Parse a CJK numeral written by the author back into a number.
+/// ```example
+/// #(conjak.parse-number("壹佰貳拾參") + 1)\
+/// #conjak.parse-number("삼만")\
+/// #conjak.parse-number("二〇二五")\
+/// #conjak.parse-number("三點一四")
+/// ```
+///
+/// - text (str): The numeral to parse
+/// - number-script (auto, str, dictionary): The script the numeral is written in, in the same form `format-number` sends to the plugin, e.g. `(japanese: "upper")`. `auto` detects it from the text
+/// - count-method (str): The counting method to use. Can be "high", "middle", "low", or "ten_thousand"
+/// -> int, float, decimal
+#let parse-number(
+  text,
+  number-script: auto,
+  count-method: "ten_thousand",
+) = {
+  let value = cbor(
+    plg.text_to_number(
+      cbor.encode((
+        text: text,
+        number_script: if number-script == auto { "auto" } else { number-script },
+        count_method: count-method,
+      )),
+    ),
+  ).value
+  // values beyond int or float precision come back as their exact decimal text
+  if type(value) == str { decimal(value) } else { value }
+}
+
+/// Format a money amount the way cheques and invoices write it in Chinese (大写金额). The amount is rounded to 分, zeros follow the banking rules, and whole amounts end with 整 (正 for Hong Kong dollars). In Korean, the amount is rounded to 원 and framed as in contracts, such as 일금 일백이십삼만원정, or 一金 壹百貳拾參萬원整 with hanja.
+/// ```example
+/// #set text(lang: "zh", region: "cn")
+/// #conjak.format-amount(12345.67)\
+/// #conjak.format-amount(decimal("1680.32"), currency: none)\
+/// #text(region: "tw", conjak.format-amount(10000))\
+/// #set text(lang: "ko", region: none)
+/// #conjak.format-amount(1230000)\
+/// #conjak.format-amount(1230000, hanja: true)
+/// ```
+///
+/// - value (int, float, str, decimal): The amount to format. Must not be negative. Use strings or decimals for exact amounts
+/// - currency (auto, none, str): The currency written in front of the amount. Can be "renminbi", "new_taiwan_dollar" or "hong_kong_dollar". `auto` picks it from the text region (cn, tw or hk), `none` writes no currency. Not used in Korean
+/// - hanja (bool): In Korean, write the hanja upper-case numerals (갖은자) instead of hangul
+/// - count-method (str): The counting method to use. Can be "high", "middle", "low", or "ten_thousand"
+/// -> str
+#let format-amount(
+  value,
+  currency: auto,
+  hanja: false,
+  count-method: "ten_thousand",
+) = context {
+  let (l, r) = (lower(text.lang), if text.region == none { "" } else { lower(text.region) })
+  let number-script = if l == "ko" {
+    (korean: if hanja { "upper" } else { (lower: (circle_as_zero: false)) })
+  } else if l != "zh" {
     panic("Unsupported language: " + l)
+  } else if r in ("hk", "mo", "tw") {
+    (traditional_chinese: "upper")
+  } else {
+    (simplified_chinese: "upper")
+  }
+  let currency = if l == "ko" {
+    none
+  } else if currency == auto {
+    (cn: "renminbi", tw: "new_taiwan_dollar", hk: "hong_kong_dollar").at(r, default: none)
+  } else {
+    currency
   }
   str(
-    plg.number_to_text(
+    plg.amount_to_text(
       cbor.encode((
-        value: value,
+        value: if type(value) == decimal { str(value) } else { value },
         number_script: number-script,
         count_method: count-method,
+        currency: currency,
       )),
     ),
   )
@@ -515,17 +829,35 @@
   }
 }
 
-/// Convert a date to the lunar calendar format.
+/// Convert a date to the lunar calendar format. Leap months are marked with 闰, 閏 (Traditional Chinese, Japanese and Vietnamese) or 윤 (Korean), such as 闰四月. The rest of the date is written the same way in every language. It returns a string, so the language of the marker is given by `lang` and `region` instead of being taken from the text.
+/// ```example
+/// #conjak.format-lunar-date(datetime(year: 2025, month: 8, day: 1))\
+/// #conjak.format-lunar-date(datetime(year: 2025, month: 8, day: 1), lang: "zh", region: "tw")\
+/// #conjak.format-lunar-date(datetime(year: 2025, month: 8, day: 1), lang: "ko")\
+/// #conjak.format-lunar-date(datetime(year: 2033, month: 12, day: 22))
+/// ```
 ///
 /// - date (datetime): The date to convert to the lunar calendar.
-/// - numeric-november (auto, bool): Whether to use "十一月" for November.
-/// - numeric-december (auto, bool): Whether to use "十二月" for December
-/// ->
+/// - numeric-november (auto, bool): Whether to use "十一月" instead of "冬月" for the eleventh month.
+/// - numeric-december (auto, bool): Whether to use "十二月" instead of "腊月" for the twelfth month.
+/// - lang (str): The language of the leap month marker: "zh", "ja", "ko" or "vi".
+/// - region (none, str): The region of the leap month marker. Chinese uses 閏 in "hk", "mo" and "tw".
+/// -> str
 #let format-lunar-date(
   date,
   numeric-november: false,
   numeric-december: false,
+  lang: "zh",
+  region: none,
 ) = {
+  let (l, r) = (lower(lang), if region == none { "" } else { lower(region) })
+  let leap = if l == "ko" {
+    "윤"
+  } else if l in ("ja", "vi") or (l == "zh" and r in ("hk", "mo", "tw")) {
+    "閏"
+  } else {
+    "闰"
+  }
   let data = cbor(
     plg.solar_to_lunisolar(
       cbor.encode((
@@ -538,10 +870,11 @@
   (
     data.year
       + "年"
+      + if data.leap { leap } else { "" }
       + if data.month == 11 and not numeric-november {
-        "腊"
-      } else if data.month == 12 and not numeric-december {
         "冬"
+      } else if data.month == 12 and not numeric-december {
+        "腊"
       } else { numbering("一", data.month) }
       + "月"
       + (
@@ -557,3 +890,153 @@
       )
   )
 }
+
+/// The zodiac animal (生肖, 十二支, 띠) of the lunar year a date falls in: 龙 or 龍 in Chinese, たつ in Japanese, 용 in Korean and Rồng in Vietnamese, where the cat (Mèo) takes the place of the rabbit. It returns a string, so the language is given by `lang` and `region` instead of being taken from the text.
+/// ```example
+/// #let date = datetime(year: 2024, month: 6, day: 1)
+/// #(conjak.lunar-zodiac(date, lang: "zh", region: "tw") + "年")\
+/// #(conjak.lunar-zodiac(date, lang: "ko") + "띠")\
+/// #("Tết năm " + conjak.lunar-zodiac(datetime(year: 2023, month: 2, day: 1), lang: "vi"))
+/// ```
+///
+/// - date (datetime): The Gregorian date.
+/// - lang (str): The language of the animal name: "zh", "ja", "ko" or "vi".
+/// - region (none, str): The region. Chinese uses traditional characters in "hk", "mo" and "tw".
+/// -> str
+#let lunar-zodiac(date, lang: "zh", region: none) = {
+  cbor(
+    plg.solar_to_lunisolar(
+      cbor.encode((
+        year: date.year(),
+        month: date.month(),
+        day: date.day(),
+        number_script: lang-number-script(lang, region, false, false),
+      )),
+    ),
+  ).zodiac
+}
+
+/// Convert a date in the lunar calendar, such as a festival or a birthday, to a Gregorian date. Dates that do not exist, such as the 30th day of a short month or a leap month the year does not have, are reported as errors.
+/// ```example
+/// #conjak.lunar-to-solar-date(2025, 8, 15).display()\
+/// #conjak.lunar-to-solar-date(2025, 6, 3, leap: true).display()
+/// ```
+///
+/// - year (int): The Gregorian year in which the lunar year begins, from 1901 to 2100.
+/// - month (int): The lunar month, from 1 to 12.
+/// - day (int): The lunar day, from 1 to 30.
+/// - leap (bool): Whether the month is the leap month (閏月) following `month`.
+/// -> datetime
+#let lunar-to-solar-date(year, month, day, leap: false) = {
+  let data = cbor(
+    plg.lunisolar_to_solar(
+      cbor.encode((
+        year: year,
+        month: month,
+        leap: leap,
+        day: day,
+      )),
+    ),
+  )
+  datetime(year: data.year, month: data.month, day: data.day)
+}
+
+/// The Four Pillars (四柱) of a date: the sexagenary (干支) year, month, day and, when the date has a time, hour. Each pillar is a dictionary with `index` (0 for 甲子), `stem` (0 for 甲), `branch` (0 for 子) and the readings `chinese` (甲子), `japanese` (きのえね) and `korean` (갑자).
+///
+/// The year pillar changes at 立春 and the month pillar at each of the 12 節 (立春, 驚蟄, 清明……), the solar terms that begin the months of the sexagenary calendar, rather than at the lunar new year and the lunar months. A date without a time counts as its end, so the day a term begins already belongs to it. The 子 hour from 23:00 belongs to the next day.
+/// ```example
+/// #let pillars = conjak.four-pillars(datetime(year: 2024, month: 2, day: 10, hour: 9, minute: 0, second: 0))
+/// #pillars.year.chinese #pillars.month.chinese #pillars.day.chinese #pillars.hour.chinese\
+/// #pillars.year.japanese\
+/// #pillars.day.korean
+/// ```
+///
+/// - date (datetime): The Gregorian date, from 1901 to 2100, optionally with a time.
+/// - utc-offset (int): The time zone of `date` in minutes ahead of UTC, which decides when the solar terms begin. It must be between -840 and 840, and the default is China Standard Time.
+/// -> dictionary
+#let four-pillars(date, utc-offset: 480) = {
+  cbor(
+    plg.four_pillars(
+      cbor.encode((
+        year: date.year(),
+        month: date.month(),
+        day: date.day(),
+        hour: date.hour(),
+        minute: date.minute(),
+        utc_offset: utc-offset,
+      )),
+    ),
+  )
+}
+
+// The time zone the calendar of a language is reckoned in, in minutes ahead of UTC.
+#let lang-utc-offset(lang) = {
+  let l = lower(lang)
+  if l in ("ja", "ko") { 540 } else if l == "vi" { 420 } else { 480 }
+}
+
+// A solar term from the plugin, with its moment as a datetime.
+#let _solar-term(term) = (
+  index: term.index,
+  longitude: term.longitude,
+  name: term.name,
+  date: datetime(
+    year: term.year,
+    month: term.month,
+    day: term.day,
+    hour: term.hour,
+    minute: term.minute,
+    second: 0,
+  ),
+)
+
+/// The 24 solar terms (二十四节气, 二十四節気, 24절기) of a year, from 小寒 to 冬至. Each term is a dictionary with `index` (0 for 小寒), `longitude` (the longitude of the sun, such as 315 for 立春), `name` and `date`, the moment the term begins, rounded to the minute. The moments are computed from the position of the sun to within about half a minute. It returns an array, so the language of the names is given by `lang` and `region` instead of being taken from the text.
+/// ```example
+/// #for term in conjak.solar-terms(2025, lang: "zh", region: "tw").slice(0, 4) [
+///   #term.name: #term.date.display("[month]/[day] [hour]:[minute]")\
+/// ]
+/// ```
+///
+/// - year (int): The Gregorian year, from 1901 to 2100.
+/// - lang (str): The language of the names: "zh", "ja", "ko" or "vi".
+/// - region (none, str): The region of the names. Chinese uses Traditional Chinese in "hk", "mo" and "tw".
+/// - utc-offset (auto, int): The time zone of the moments in minutes ahead of UTC. With `auto`, it is the time zone of the calendar of `lang`: UTC+8 for Chinese, UTC+9 for Japanese and Korean, UTC+7 for Vietnamese. It must be between -840 and 840.
+/// -> array
+#let solar-terms(year, lang: "zh", region: none, utc-offset: auto) = {
+  cbor(
+    plg.solar_terms(
+      cbor.encode((
+        year: year,
+        number_script: lang-number-script(lang, region, false, false),
+        utc_offset: if utc-offset == auto { lang-utc-offset(lang) } else { utc-offset },
+      )),
+    ),
+  ).map(_solar-term)
+}
+
+/// The solar term a date falls in. The result is a dictionary like those of `solar-terms`, with `days` added: the number of days since the term began, 0 on the day it begins.
+/// ```example
+/// #let term = conjak.solar-term(datetime(year: 2025, month: 4, day: 4))
+/// #if term.days == 0 [Today is #term.name.]
+/// #conjak.solar-term(datetime(year: 2025, month: 12, day: 25), lang: "ko").name
+/// ```
+///
+/// - date (datetime): The Gregorian date, from 1901 to 2100.
+/// - lang (str): The language of the name: "zh", "ja", "ko" or "vi".
+/// - region (none, str): The region of the name. Chinese uses Traditional Chinese in "hk", "mo" and "tw".
+/// - utc-offset (auto, int): The time zone the date is in, as for `solar-terms`.
+/// -> dictionary
+#let solar-term(date, lang: "zh", region: none, utc-offset: auto) = {
+  let data = cbor(
+    plg.solar_term_of_date(
+      cbor.encode((
+        year: date.year(),
+        month: date.month(),
+        day: date.day(),
+        number_script: lang-number-script(lang, region, false, false),
+        utc_offset: if utc-offset == auto { lang-utc-offset(lang) } else { utc-offset },
+      )),
+    ),
+  )
+  _solar-term(data.term) + (days: data.days)
+}
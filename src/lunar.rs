@@ -1,12 +1,21 @@
-use chinese_lunisolar_calendar::{LunisolarDate, SolarDate};
+use std::ops::RangeInclusive;
+
+use chinese_lunisolar_calendar::{
+    LunarMonth, LunisolarDate, LunisolarDateError, LunisolarYear, SolarDate, SolarYear,
+};
 use ciborium::{from_reader, into_writer};
+use conjak_numbers::{NumberScript, ScriptStyle};
 use serde::{Deserialize, Serialize};
 
+pub(crate) mod sun;
+
 #[derive(Deserialize)]
 struct InputDate {
     year: u16,
     month: u8,
     day: u8,
+    /// The language of the zodiac animal
+    number_script: Option<NumberScript>,
 }
 
 impl TryFrom<InputDate> for SolarDate {
@@ -16,32 +25,624 @@ impl TryFrom<InputDate> for SolarDate {
     }
 }
 
+/// The lunisolar years chinese-lunisolar-calendar has data for
+const LUNISOLAR_YEARS: RangeInclusive<u16> = 1901..=2100;
+
+/// China Standard Time, which the four pillars are reckoned in by default
+const DEFAULT_UTC_OFFSET: i32 = 8 * 60;
+/// The UTC offsets in minutes, up to the 14 hours either way that time zones go
+const UTC_OFFSETS: RangeInclusive<i32> = -14 * 60..=14 * 60;
+
+#[derive(Deserialize)]
+struct InputLunisolarDate {
+    year: u16,
+    month: u8,
+    #[serde(default)]
+    leap: bool,
+    day: u8,
+}
+
+impl TryFrom<InputLunisolarDate> for LunisolarDate {
+    type Error = String;
+    fn try_from(input: InputLunisolarDate) -> Result<Self, Self::Error> {
+        let InputLunisolarDate {
+            year,
+            month,
+            leap,
+            day,
+        } = input;
+        let out_of_range = format!("the lunisolar year {year} is out of the supported range");
+        // chinese-lunisolar-calendar accepts the year 2101 but has no data for it and panics
+        if !LUNISOLAR_YEARS.contains(&year) {
+            return Err(out_of_range);
+        }
+        let month_name = if leap {
+            format!("leap month {month}")
+        } else {
+            format!("month {month}")
+        };
+        LunisolarDate::from_ymd(year, month, leap, day).map_err(|e| match e {
+            LunisolarDateError::OutOfRange | LunisolarDateError::YearIncorrect => out_of_range,
+            LunisolarDateError::MonthIncorrect if leap && (1..=12).contains(&month) => {
+                format!("the lunisolar year {year} has no {month_name}")
+            }
+            LunisolarDateError::MonthIncorrect => {
+                format!("the lunar month must be between 1 and 12, got {month}")
+            }
+            LunisolarDateError::DayIncorrect => {
+                let days = LunisolarYear::from_solar_year(SolarYear::from_u16(year))
+                    .ok()
+                    .zip(LunarMonth::from_u8_with_leap(month, leap).ok())
+                    .and_then(|(y, m)| y.get_total_days_in_a_month(m));
+                match days {
+                    Some(days) if (1..=30).contains(&day) => format!(
+                        "{month_name} of the lunisolar year {year} has {days} days, so day {day} does not exist"
+                    ),
+                    _ => format!("the lunar day must be between 1 and 30, got {day}"),
+                }
+            }
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct OutputSolarDate {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl From<SolarDate> for OutputSolarDate {
+    fn from(date: SolarDate) -> Self {
+        OutputSolarDate {
+            year: date.to_solar_year().to_u16(),
+            month: date.to_solar_month().to_u8(),
+            day: date.to_solar_day().to_u8(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct InputDateTime {
+    year: u16,
+    month: u8,
+    day: u8,
+    /// The hour from 0 to 23. The hour pillar is left out without it
+    hour: Option<u8>,
+    minute: Option<u8>,
+    /// Minutes ahead of UTC the time is given in, 480 for China Standard Time
+    utc_offset: Option<i32>,
+}
+
+const STEMS: [&str; 10] = ["甲", "乙", "丙", "丁", "戊", "己", "庚", "辛", "壬", "癸"];
+const BRANCHES: [&str; 12] = [
+    "子", "丑", "寅", "卯", "辰", "巳", "午", "未", "申", "酉", "戌", "亥",
+];
+const STEMS_JAPANESE: [&str; 10] = [
+    "きのえ",
+    "きのと",
+    "ひのえ",
+    "ひのと",
+    "つちのえ",
+    "つちのと",
+    "かのえ",
+    "かのと",
+    "みずのえ",
+    "みずのと",
+];
+const BRANCHES_JAPANESE: [&str; 12] = [
+    "ね",
+    "うし",
+    "とら",
+    "う",
+    "たつ",
+    "み",
+    "うま",
+    "ひつじ",
+    "さる",
+    "とり",
+    "いぬ",
+    "い",
+];
+const STEMS_KOREAN: [&str; 10] = ["갑", "을", "병", "정", "무", "기", "경", "신", "임", "계"];
+const BRANCHES_KOREAN: [&str; 12] = [
+    "자", "축", "인", "묘", "진", "사", "오", "미", "신", "유", "술", "해",
+];
+
+/// One pillar (柱) of the sexagenary cycle, such as 甲子
+#[derive(Serialize)]
+struct OutputPillar {
+    /// Position in the sexagenary cycle, 0 for 甲子 and 59 for 癸亥
+    index: u8,
+    /// The heavenly stem (天干), 0 for 甲
+    stem: u8,
+    /// The earthly branch (地支), 0 for 子
+    branch: u8,
+    chinese: String,
+    japanese: String,
+    korean: String,
+}
+
+impl OutputPillar {
+    fn from_stem_branch(stem: u8, branch: u8) -> Self {
+        let (s, b) = (stem as usize, branch as usize);
+        OutputPillar {
+            // The number n with n % 10 == stem and n % 12 == branch
+            index: ((6 * stem as u16 + 55 * branch as u16) % 60) as u8,
+            stem,
+            branch,
+            chinese: [STEMS[s], BRANCHES[b]].concat(),
+            japanese: [STEMS_JAPANESE[s], BRANCHES_JAPANESE[b]].concat(),
+            korean: [STEMS_KOREAN[s], BRANCHES_KOREAN[b]].concat(),
+        }
+    }
+
+    fn from_index(index: u8) -> Self {
+        Self::from_stem_branch(index % 10, index % 12)
+    }
+}
+
+/// The Four Pillars (四柱). The year pillar changes at 立春 and the month pillar at each of the 12 節 (立春, 驚蟄, 清明……), so they follow the solar terms rather than the lunar months
+#[derive(Serialize)]
+struct OutputFourPillars {
+    year: OutputPillar,
+    month: OutputPillar,
+    day: OutputPillar,
+    hour: Option<OutputPillar>,
+}
+
+/// Days since 0001-01-01 in the proleptic Gregorian calendar
+fn days_from_common_era(year: u16, month: u8, day: u8) -> i64 {
+    let (y, m) = if month <= 2 {
+        (year as i64 - 1, month as i64 + 12)
+    } else {
+        (year as i64, month as i64)
+    };
+    365 * y + y / 4 - y / 100 + y / 400 + (153 * (m - 3) + 2) / 5 + day as i64 - 307
+}
+
+const ZODIAC_SIMPLIFIED: [&str; 12] = [
+    "鼠", "牛", "虎", "兔", "龙", "蛇", "马", "羊", "猴", "鸡", "狗", "猪",
+];
+const ZODIAC_TRADITIONAL: [&str; 12] = [
+    "鼠", "牛", "虎", "兔", "龍", "蛇", "馬", "羊", "猴", "雞", "狗", "豬",
+];
+const ZODIAC_JAPANESE: [&str; 12] = [
+    "ねずみ",
+    "うし",
+    "とら",
+    "うさぎ",
+    "たつ",
+    "へび",
+    "うま",
+    "ひつじ",
+    "さる",
+    "とり",
+    "いぬ",
+    "いのしし",
+];
+const ZODIAC_KOREAN: [&str; 12] = [
+    "쥐",
+    "소",
+    "호랑이",
+    "토끼",
+    "용",
+    "뱀",
+    "말",
+    "양",
+    "원숭이",
+    "닭",
+    "개",
+    "돼지",
+];
+// The Vietnamese zodiac has the water buffalo, the cat and the goat in place of the ox, the rabbit and the sheep
+const ZODIAC_VIETNAMESE: [&str; 12] = [
+    "Chuột", "Trâu", "Hổ", "Mèo", "Rồng", "Rắn", "Ngựa", "Dê", "Khỉ", "Gà", "Chó", "Lợn",
+];
+
+/// The zodiac animal of an earthly branch, 0 for 子. Japanese uses the animal names in kana and has the wild boar for 亥, and Vietnamese uses Quốc ngữ
+fn zodiac_to_str(number_script: NumberScript, branch: u8) -> &'static str {
+    let names = match number_script {
+        NumberScript::SimplifiedChinese(_) => &ZODIAC_SIMPLIFIED,
+        NumberScript::TraditionalChinese(_) => &ZODIAC_TRADITIONAL,
+        NumberScript::Japanese(_) => &ZODIAC_JAPANESE,
+        NumberScript::Korean(_) => &ZODIAC_KOREAN,
+        NumberScript::Vietnamese(_) => &ZODIAC_VIETNAMESE,
+        // Scripts added to `conjak-numbers` later write the names in Traditional Chinese
+        _ => &ZODIAC_TRADITIONAL,
+    };
+    names[branch as usize]
+}
+
+/// Position of the lunar year in the sexagenary cycle, 0 for 甲子 such as 1984
+fn sexagenary_year_index(date: LunisolarDate) -> u8 {
+    (date.to_lunisolar_year().to_u16() as i64 - 1984).rem_euclid(60) as u8
+}
+
 #[derive(Serialize)]
 struct OutputLunisolarDate {
     year: String,
     month: u8,
+    /// Whether the month is a leap month (閏月), which repeats the number of the month before it
+    leap: bool,
     day: u8,
+    /// The zodiac animal of the year (生肖), such as 龍 or 용
+    zodiac: &'static str,
 }
 
-impl From<LunisolarDate> for OutputLunisolarDate {
-    fn from(date: LunisolarDate) -> Self {
+impl OutputLunisolarDate {
+    fn new(date: LunisolarDate, number_script: NumberScript) -> Self {
         let year = date.to_lunar_year();
         let month = date.to_lunar_month();
         let day = date.to_lunar_day();
         OutputLunisolarDate {
             year: year.to_string(),
             month: month.to_u8(),
+            leap: month.is_leap_month(),
             day: day.to_u8(),
+            zodiac: zodiac_to_str(number_script, sexagenary_year_index(date) % 12),
         }
     }
 }
 
 pub fn solar_to_lunisolar(input: &[u8]) -> Result<Vec<u8>, String> {
     let input: InputDate = from_reader(input).map_err(|e| e.to_string())?;
+    let number_script = input
+        .number_script
+        .unwrap_or(NumberScript::SimplifiedChinese(ScriptStyle::Lower {
+            circle_as_zero: false,
+        }));
     let solar_date: SolarDate = input.try_into()?;
     let lunisolar_date = LunisolarDate::from_solar_date(solar_date).map_err(|e| e.to_string())?;
-    let output: OutputLunisolarDate = lunisolar_date.into();
+    let output = OutputLunisolarDate::new(lunisolar_date, number_script);
+    let mut output_bytes = Vec::new();
+    into_writer(&output, &mut output_bytes).map_err(|e| e.to_string())?;
+    Ok(output_bytes)
+}
+
+pub fn lunisolar_to_solar(input: &[u8]) -> Result<Vec<u8>, String> {
+    let input: InputLunisolarDate = from_reader(input).map_err(|e| e.to_string())?;
+    let lunisolar_date: LunisolarDate = input.try_into()?;
+    let output: OutputSolarDate = lunisolar_date.to_solar_date().into();
     let mut output_bytes = Vec::new();
     into_writer(&output, &mut output_bytes).map_err(|e| e.to_string())?;
     Ok(output_bytes)
 }
+
+pub fn four_pillars(input: &[u8]) -> Result<Vec<u8>, String> {
+    let input: InputDateTime = from_reader(input).map_err(|e| e.to_string())?;
+    if input.hour.is_some_and(|hour| hour > 23) {
+        return Err("the hour must be between 0 and 23".to_string());
+    }
+    if input.minute.is_some_and(|minute| minute > 59) {
+        return Err("the minute must be between 0 and 59".to_string());
+    }
+    if !LUNISOLAR_YEARS.contains(&input.year) {
+        return Err(format!(
+            "the year must be between {} and {}, got {}",
+            LUNISOLAR_YEARS.start(),
+            LUNISOLAR_YEARS.end(),
+            input.year
+        ));
+    }
+    SolarDate::from_ymd(input.year, input.month, input.day).map_err(|e| e.to_string())?;
+    let utc_offset = input.utc_offset.unwrap_or(DEFAULT_UTC_OFFSET);
+    if !UTC_OFFSETS.contains(&utc_offset) {
+        return Err(format!(
+            "the UTC offset must be between {} and {} minutes, got {utc_offset}",
+            UTC_OFFSETS.start(),
+            UTC_OFFSETS.end()
+        ));
+    }
+
+    // Without a time the whole day counts, so a term beginning that day already applies
+    let minutes = match input.hour {
+        Some(hour) => hour as u16 * 60 + input.minute.unwrap_or(0) as u16,
+        None => 24 * 60 - 1,
+    };
+    let moment = sun::julian_day(
+        input.year as i32,
+        input.month,
+        input.day as f64 + minutes as f64 / 1440.0,
+    );
+    let has_begun = |year: u16, index: usize| {
+        sun::solar_term_julian_day(year, index) + utc_offset as f64 / 1440.0 <= moment
+    };
+
+    // The sexagenary year begins at 立春, the term with index 2
+    let solar_year = if has_begun(input.year, 2) {
+        input.year
+    } else {
+        input.year - 1
+    };
+    let year_index = (solar_year as i64 - 1984).rem_euclid(60) as u8;
+    // The 節 are the terms with even indices, from 小寒 (丑) to 大雪 (子). Before 小寒, the date is
+    // still in the 子 month that began at 大雪 of the year before
+    let month_branch = (0..24)
+        .step_by(2)
+        .rev()
+        .find(|&index| has_begun(input.year, index))
+        .map_or(0, |index| (index as u8 / 2 + 1) % 12);
+    // 五虎遁: the 寅 month of a 甲 or 己 year is 丙寅
+    let month_stem = ((year_index % 5) * 2 + 2 + (month_branch + 10) % 12) % 10;
+    // 0001-01-01 is 己卯, the 16th day of the cycle. The 子 hour from 23:00 already belongs to the next day
+    let mut days = days_from_common_era(input.year, input.month, input.day);
+    if input.hour == Some(23) {
+        days += 1;
+    }
+    let day_index = (days + 15).rem_euclid(60) as u8;
+    // 五鼠遁: the 子 hour of a 甲 or 己 day is 甲子
+    let hour = input.hour.map(|hour| {
+        let branch = hour.div_ceil(2) % 12;
+        OutputPillar::from_stem_branch(((day_index % 5) * 2 + branch) % 10, branch)
+    });
+
+    let output = OutputFourPillars {
+        year: OutputPillar::from_index(year_index),
+        month: OutputPillar::from_stem_branch(month_stem, month_branch),
+        day: OutputPillar::from_index(day_index),
+        hour,
+    };
+    let mut output_bytes = Vec::new();
+    into_writer(&output, &mut output_bytes).map_err(|e| e.to_string())?;
+    Ok(output_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct LunisolarDateOutput {
+        year: String,
+        month: u8,
+        leap: bool,
+        day: u8,
+        zodiac: String,
+    }
+
+    #[derive(Deserialize)]
+    struct SolarDateOutput {
+        year: u16,
+        month: u8,
+        day: u8,
+    }
+
+    #[derive(Serialize)]
+    struct SolarDateInput {
+        year: u16,
+        month: u8,
+        day: u8,
+    }
+
+    #[derive(Serialize)]
+    struct DateTimeInput {
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: Option<u8>,
+        utc_offset: Option<i32>,
+    }
+
+    #[derive(Deserialize)]
+    struct PillarOutput {
+        chinese: String,
+    }
+
+    #[derive(Deserialize)]
+    struct FourPillarsOutput {
+        year: PillarOutput,
+        month: PillarOutput,
+        day: PillarOutput,
+        hour: Option<PillarOutput>,
+    }
+
+    #[derive(Serialize)]
+    struct LunisolarDateInput {
+        year: u16,
+        month: u8,
+        leap: bool,
+        day: u8,
+    }
+
+    fn encode(value: &impl Serialize) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        into_writer(value, &mut bytes).unwrap();
+        bytes
+    }
+
+    fn to_lunisolar(year: u16, month: u8, day: u8) -> LunisolarDateOutput {
+        let input = encode(&SolarDateInput { year, month, day });
+        from_reader(solar_to_lunisolar(&input).unwrap().as_slice()).unwrap()
+    }
+
+    fn to_solar(year: u16, month: u8, leap: bool, day: u8) -> Result<(u16, u8, u8), String> {
+        let input = encode(&LunisolarDateInput {
+            year,
+            month,
+            leap,
+            day,
+        });
+        let output: SolarDateOutput = from_reader(lunisolar_to_solar(&input)?.as_slice()).unwrap();
+        Ok((output.year, output.month, output.day))
+    }
+
+    fn pillars(year: u16, month: u8, day: u8, hour: Option<u8>) -> [String; 4] {
+        let input = encode(&DateTimeInput {
+            year,
+            month,
+            day,
+            hour,
+            utc_offset: None,
+        });
+        let output: FourPillarsOutput =
+            from_reader(four_pillars(&input).unwrap().as_slice()).unwrap();
+        [
+            output.year.chinese,
+            output.month.chinese,
+            output.day.chinese,
+            output.hour.map_or_else(String::new, |hour| hour.chinese),
+        ]
+    }
+
+    #[test]
+    fn leap_month() {
+        // 閏二月初一 of 癸卯
+        let date = to_lunisolar(2023, 3, 22);
+        assert_eq!(
+            ("癸卯", 2, true, 1),
+            (date.year.as_str(), date.month, date.leap, date.day)
+        );
+        assert_eq!("兔", date.zodiac);
+
+        // The ordinary 二月 just before it
+        let date = to_lunisolar(2023, 3, 21);
+        assert_eq!((2, false, 30), (date.month, date.leap, date.day));
+
+        // 閏六月初八 of 乙巳
+        let date = to_lunisolar(2025, 8, 1);
+        assert_eq!((6, true, 8), (date.month, date.leap, date.day));
+
+        let date = to_lunisolar(2025, 6, 1);
+        assert_eq!((5, false, 6), (date.month, date.leap, date.day));
+
+        // 閏十一月初一 of 癸丑, the leap 冬月
+        let date = to_lunisolar(2033, 12, 22);
+        assert_eq!((11, true, 1), (date.month, date.leap, date.day));
+        assert_eq!(Ok((2033, 12, 22)), to_solar(2033, 11, true, 1));
+    }
+
+    #[test]
+    fn lunisolar_to_solar_errors() {
+        assert_eq!(
+            Err("the lunisolar year 2024 has no leap month 6".to_string()),
+            to_solar(2024, 6, true, 1)
+        );
+        assert_eq!(
+            Err(
+                "month 2 of the lunisolar year 2025 has 29 days, so day 30 does not exist"
+                    .to_string()
+            ),
+            to_solar(2025, 2, false, 30)
+        );
+        assert_eq!(
+            Err(
+                "leap month 6 of the lunisolar year 2025 has 29 days, so day 30 does not exist"
+                    .to_string()
+            ),
+            to_solar(2025, 6, true, 30)
+        );
+        assert_eq!(
+            Err("the lunar day must be between 1 and 30, got 31".to_string()),
+            to_solar(2025, 1, false, 31)
+        );
+        assert_eq!(
+            Err("the lunar month must be between 1 and 12, got 13".to_string()),
+            to_solar(2025, 13, false, 1)
+        );
+        assert_eq!(
+            Err("the lunisolar year 1900 is out of the supported range".to_string()),
+            to_solar(1900, 1, false, 1)
+        );
+        assert_eq!(
+            Err("the lunisolar year 2101 is out of the supported range".to_string()),
+            to_solar(2101, 1, false, 1)
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        // 中秋節 and the first day of the leap month
+        assert_eq!(Ok((2025, 10, 6)), to_solar(2025, 8, false, 15));
+        assert_eq!(Ok((2025, 7, 25)), to_solar(2025, 6, true, 1));
+
+        for year in 1902..=2099 {
+            for month in 1..=12 {
+                for day in [1, 15, 28] {
+                    let date = to_lunisolar(year, month, day);
+                    // The 11th and 12th lunar months early in a year belong to the lunar year before
+                    let lunar_year = if date.month >= 11 && month <= 2 {
+                        year - 1
+                    } else {
+                        year
+                    };
+                    assert_eq!(
+                        Ok((year, month, day)),
+                        to_solar(lunar_year, date.month, date.leap, date.day)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn four_pillars_day_and_hour() {
+        assert_eq!(["己卯", "丙子", "戊午", ""], pillars(2000, 1, 1, None));
+        assert_eq!(
+            ["己卯", "丙子", "戊午", "壬子"],
+            pillars(2000, 1, 1, Some(0))
+        );
+        assert_eq!(
+            ["己卯", "丙子", "戊午", "戊午"],
+            pillars(2000, 1, 1, Some(12))
+        );
+        // The 子 hour from 23:00 belongs to the next day
+        assert_eq!(
+            ["己卯", "丙子", "己未", "甲子"],
+            pillars(2000, 1, 1, Some(23))
+        );
+        assert_eq!(
+            ["己卯", "丙子", "己未", "甲子"],
+            pillars(2000, 1, 2, Some(0))
+        );
+    }
+
+    #[test]
+    fn four_pillars_solar_terms() {
+        // 立春 of 2024 began at 16:27 China Standard Time, after the lunar new year of 2024
+        assert_eq!(
+            ["癸卯", "乙丑", "戊戌", "庚申"],
+            pillars(2024, 2, 4, Some(16))
+        );
+        assert_eq!(
+            ["甲辰", "丙寅", "戊戌", "辛酉"],
+            pillars(2024, 2, 4, Some(17))
+        );
+        assert_eq!(["甲辰", "丙寅", "甲辰", ""], pillars(2024, 2, 10, None));
+        // 立春 of 2023 fell before the lunar new year of 2023
+        assert_eq!(["壬寅", "癸丑", "己卯", ""], pillars(2023, 1, 21, None));
+        assert_eq!(["癸卯", "甲寅", "癸巳", ""], pillars(2023, 2, 4, None));
+        // 大雪 of 2023 on December 7 starts the 子 month
+        assert_eq!(["癸卯", "癸亥", "戊戌", ""], pillars(2023, 12, 6, None));
+        assert_eq!(["癸卯", "甲子", "己亥", ""], pillars(2023, 12, 7, None));
+    }
+
+    #[test]
+    fn four_pillars_utc_offset() {
+        let year_pillar = |hour, utc_offset| {
+            let input = encode(&DateTimeInput {
+                year: 2024,
+                month: 2,
+                day: 4,
+                hour: Some(hour),
+                utc_offset: Some(utc_offset),
+            });
+            let output: FourPillarsOutput = from_reader(four_pillars(&input)?.as_slice()).unwrap();
+            Ok::<_, String>(output.year.chinese)
+        };
+        // 立春 of 2024 began at 08:27 UTC
+        assert_eq!(Ok("癸卯".to_string()), year_pillar(8, 0));
+        assert_eq!(Ok("甲辰".to_string()), year_pillar(9, 0));
+        // 22:00 at UTC+14 is 08:00 UTC, and 00:00 at UTC−14 is 14:00 UTC
+        assert_eq!(Ok("癸卯".to_string()), year_pillar(22, 14 * 60));
+        assert_eq!(Ok("甲辰".to_string()), year_pillar(0, -14 * 60));
+        assert_eq!(
+            Err("the UTC offset must be between -840 and 840 minutes, got 841".to_string()),
+            year_pillar(12, 841)
+        );
+        assert_eq!(
+            Err("the UTC offset must be between -840 and 840 minutes, got -841".to_string()),
+            year_pillar(12, -841)
+        );
+    }
+}
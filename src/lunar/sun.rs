@@ -0,0 +1,140 @@
+//! The apparent position of the sun, which the sexagenary year and month pillars follow
+
+mod vsop87;
+
+/// Julian day number of 0h UT on a Gregorian date, which may have a fractional day
+pub(crate) fn julian_day(year: i32, month: u8, day: f64) -> f64 {
+    let (y, m) = if month <= 2 {
+        (year - 1, month as i32 + 12)
+    } else {
+        (year, month as i32)
+    };
+    let a = y.div_euclid(100);
+    let b = 2 - a + a.div_euclid(4);
+    (365.25 * (y + 4716) as f64).floor() + (30.6001 * (m + 1) as f64).floor() + day + b as f64
+        - 1524.5
+}
+
+/// ΔT = TT − UT in seconds, from the polynomials of Espenak and Meeus, refitted to the values
+/// observed from 2005 to 2025
+fn delta_t(year: f64) -> f64 {
+    if year < 1920.0 {
+        let t = year - 1900.0;
+        -2.79 + 1.494119 * t - 0.0598939 * t.powi(2) + 0.0061966 * t.powi(3) - 0.000197 * t.powi(4)
+    } else if year < 1941.0 {
+        let t = year - 1920.0;
+        21.20 + 0.84493 * t - 0.076100 * t.powi(2) + 0.0020936 * t.powi(3)
+    } else if year < 1961.0 {
+        let t = year - 1950.0;
+        29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+    } else if year < 1986.0 {
+        let t = year - 1975.0;
+        45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+    } else if year < 2005.0 {
+        let t = year - 2000.0;
+        63.86 + 0.3345 * t - 0.060374 * t.powi(2)
+            + 0.0017275 * t.powi(3)
+            + 0.000651814 * t.powi(4)
+            + 0.00002373599 * t.powi(5)
+    } else if year < 2025.0 {
+        // Fitted to the values observed by the IERS, which have fallen well short of the
+        // polynomial of Espenak and Meeus since about 2015
+        let t = year - 2005.0;
+        64.79 + 0.08726 * t + 0.03706 * t.powi(2) - 0.0015438 * t.powi(3)
+    } else if year < 2050.0 {
+        // Rejoins the extrapolation of Espenak and Meeus by 2050
+        let t = year - 2025.0;
+        69.0 + 0.96 * t
+    } else {
+        -20.0 + 32.0 * ((year - 1820.0) / 100.0).powi(2) - 0.5628 * (2150.0 - year)
+    }
+}
+
+/// The multiples of the arguments D, M, M′, F and Ω, and the coefficients in 0.0001″ of the
+/// largest terms of the IAU 1980 nutation in longitude, from Meeus, *Astronomical Algorithms*,
+/// Table 22.A. Each term left out is below 0.0015″
+const NUTATION: [([f64; 5], f64, f64); 31] = [
+    ([0.0, 0.0, 0.0, 0.0, 1.0], -171996.0, -174.2),
+    ([-2.0, 0.0, 0.0, 2.0, 2.0], -13187.0, -1.6),
+    ([0.0, 0.0, 0.0, 2.0, 2.0], -2274.0, -0.2),
+    ([0.0, 0.0, 0.0, 0.0, 2.0], 2062.0, 0.2),
+    ([0.0, 1.0, 0.0, 0.0, 0.0], 1426.0, -3.4),
+    ([0.0, 0.0, 1.0, 0.0, 0.0], 712.0, 0.1),
+    ([-2.0, 1.0, 0.0, 2.0, 2.0], -517.0, 1.2),
+    ([0.0, 0.0, 0.0, 2.0, 1.0], -386.0, -0.4),
+    ([0.0, 0.0, 1.0, 2.0, 2.0], -301.0, 0.0),
+    ([-2.0, -1.0, 0.0, 2.0, 2.0], 217.0, -0.5),
+    ([-2.0, 0.0, 1.0, 0.0, 0.0], -158.0, 0.0),
+    ([-2.0, 0.0, 0.0, 2.0, 1.0], 129.0, 0.1),
+    ([0.0, 0.0, -1.0, 2.0, 2.0], 123.0, 0.0),
+    ([2.0, 0.0, 0.0, 0.0, 0.0], 63.0, 0.0),
+    ([0.0, 0.0, 1.0, 0.0, 1.0], 63.0, 0.1),
+    ([2.0, 0.0, -1.0, 2.0, 2.0], -59.0, 0.0),
+    ([0.0, 0.0, -1.0, 0.0, 1.0], -58.0, -0.1),
+    ([0.0, 0.0, 1.0, 2.0, 1.0], -51.0, 0.0),
+    ([-2.0, 0.0, 2.0, 0.0, 0.0], 48.0, 0.0),
+    ([0.0, 0.0, -2.0, 2.0, 1.0], 46.0, 0.0),
+    ([2.0, 0.0, 0.0, 2.0, 2.0], -38.0, 0.0),
+    ([0.0, 0.0, 2.0, 2.0, 2.0], -31.0, 0.0),
+    ([0.0, 0.0, 2.0, 0.0, 0.0], 29.0, 0.0),
+    ([-2.0, 0.0, 1.0, 2.0, 2.0], 29.0, 0.0),
+    ([0.0, 0.0, 0.0, 2.0, 0.0], 26.0, 0.0),
+    ([-2.0, 0.0, 0.0, 2.0, 0.0], -22.0, 0.0),
+    ([0.0, 0.0, -1.0, 2.0, 1.0], 21.0, 0.0),
+    ([0.0, 2.0, 0.0, 0.0, 0.0], 17.0, -0.1),
+    ([2.0, 0.0, -1.0, 0.0, 1.0], 16.0, 0.0),
+    ([-2.0, 2.0, 0.0, 2.0, 2.0], -16.0, 0.1),
+    ([0.0, 1.0, 0.0, 0.0, 1.0], -15.0, 0.0),
+];
+
+/// The nutation in longitude Δψ in arcseconds at `t` Julian centuries of TT from J2000.0
+fn nutation_in_longitude(t: f64) -> f64 {
+    // The mean elongation of the moon, the mean anomalies of the sun and the moon, the argument of
+    // latitude of the moon and the longitude of the ascending node of the moon, in degrees
+    let arguments = [
+        297.85036 + 445267.111480 * t - 0.0019142 * t * t + t.powi(3) / 189474.0,
+        357.52772 + 35999.050340 * t - 0.0001603 * t * t - t.powi(3) / 300000.0,
+        134.96298 + 477198.867398 * t + 0.0086972 * t * t + t.powi(3) / 56250.0,
+        93.27191 + 483202.017538 * t - 0.0036825 * t * t + t.powi(3) / 327270.0,
+        125.04452 - 1934.136261 * t + 0.0020708 * t * t + t.powi(3) / 450000.0,
+    ];
+    NUTATION
+        .iter()
+        .map(|(multiples, a, b)| {
+            let argument: f64 = multiples.iter().zip(arguments).map(|(k, x)| k * x).sum();
+            (a + b * t) * argument.to_radians().sin()
+        })
+        .sum::<f64>()
+        / 10000.0
+}
+
+/// The apparent longitude of the sun in degrees at a Julian ephemeris day, from VSOP87 with the
+/// corrections for FK5, nutation and aberration, accurate to about 1″
+fn apparent_solar_longitude(jde: f64) -> f64 {
+    let t = (jde - 2451545.0) / 36525.0;
+    let (l, _, r) = vsop87::earth(t / 10.0);
+    // Geocentric, so the sun is opposite the Earth
+    let longitude = l.to_degrees() + 180.0;
+    let fk5 = -0.09033 / 3600.0;
+    let nutation = nutation_in_longitude(t) / 3600.0;
+    let aberration = -20.4898 / 3600.0 / r;
+    (longitude + fk5 + nutation + aberration).rem_euclid(360.0)
+}
+
+/// The Julian day (UT) on which the sun reaches the longitude of a solar term of a year, 0 for 小寒
+pub(crate) fn solar_term_julian_day(year: u16, index: usize) -> f64 {
+    let target = (285.0 + 15.0 * index as f64).rem_euclid(360.0);
+    let delta_t = delta_t(year as f64) / 86400.0;
+    // 小寒 falls around January 6, and the terms are about 15.2 days apart
+    let mut jd = julian_day(year as i32, 1, 6.0) + 15.2184 * index as f64;
+    for _ in 0..10 {
+        let diff =
+            (target - apparent_solar_longitude(jd + delta_t) + 180.0).rem_euclid(360.0) - 180.0;
+        let step = diff * 365.2422 / 360.0;
+        jd += step;
+        if step.abs() < 1e-7 {
+            break;
+        }
+    }
+    jd
+}
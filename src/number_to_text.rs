@@ -1,12 +1,45 @@
 use ciborium::from_reader;
-use conjak_numbers::{ChineseCountMethod, NumberScript, NumberToChinese, ScriptStyle};
+use conjak_numbers::{
+    ChineseCountMethod, CompactFormat, Decimal, DigitFormat, FractionFormat, KanaFormat,
+    LocalePreset, LocaleRules, MixedFormat, NumberScript, NumberToChinese, NumberToChineseError,
+    NumberToChineseWithSpacing, NumberToCompact, NumberToDigits, NumberToJapaneseKana,
+    NumberToMixed, NumberToQuocNgu, NumberToRomanization, RomanizationSystem, ScriptStyle,
+    SpacingFormat, VietnameseDialect,
+};
 use serde::Deserialize;
 
 #[derive(Deserialize)]
 #[serde(untagged)]
-enum NumberInput {
+pub(crate) enum NumberInput {
     Integer(i64),
     Float(f64),
+    /// Exact decimal string such as "123456789012345678901234567890.125"
+    Decimal(String),
+}
+
+impl NumberInput {
+    /// The exact value. Infinite and NaN floats give the same errors as in `conjak-numbers`
+    pub(crate) fn into_decimal(self) -> Result<Decimal, String> {
+        match self {
+            NumberInput::Integer(i) => Ok(Decimal::from(i)),
+            NumberInput::Float(f) => Decimal::from_f64(f).ok_or_else(|| {
+                if f < 0.0 {
+                    NumberToChineseError::Underflow.to_string()
+                } else {
+                    NumberToChineseError::Overflow.to_string()
+                }
+            }),
+            NumberInput::Decimal(d) => d.parse::<Decimal>().map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// Either the name of a built-in region or every rule spelled out
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RulesInput {
+    Preset(LocalePreset),
+    Custom(LocaleRules),
 }
 
 #[derive(Deserialize)]
@@ -14,21 +47,134 @@ struct Input {
     value: NumberInput,
     number_script: Option<NumberScript>,
     count_method: Option<ChineseCountMethod>,
+    /// How the fractional part is read. `None` keeps the default of each value type
+    fraction: Option<FractionFormat>,
+    /// Write the Japanese kana reading instead of the numeral
+    kana: Option<KanaFormat>,
+    /// Write the Vietnamese reading in Quốc ngữ instead of the numeral
+    quoc_ngu: Option<VietnameseDialect>,
+    /// Write pinyin or Jyutping instead of the numeral
+    romanization: Option<RomanizationSystem>,
+    /// Abbreviate with the largest unit, such as 12.3万
+    compact: Option<CompactFormat>,
+    /// Write Arabic digits with the myriad units, such as 1億2345万6789
+    mixed: Option<MixedFormat>,
+    /// Write grouped Arabic digits, such as 123,456,789 or 1,2345,6789
+    digits: Option<DigitFormat>,
+    /// Separate the myriad groups and drop the leading 一, such as 십이억 삼천사백오십육만
+    spacing: Option<SpacingFormat>,
+    /// When to drop the leading 一 and write 兩, such as 两千 in mainland China
+    rules: Option<RulesInput>,
+}
+
+impl Input {
+    /// Each option writes the number in its own way, so at most one of them can be set. The only
+    /// exception is `fraction` with `rules`, which both shape the plain numeral
+    fn check_options(&self) -> Result<(), String> {
+        let options = [
+            ("fraction", self.fraction.is_some()),
+            ("rules", self.rules.is_some()),
+            ("kana", self.kana.is_some()),
+            ("quoc_ngu", self.quoc_ngu.is_some()),
+            ("romanization", self.romanization.is_some()),
+            ("compact", self.compact.is_some()),
+            ("mixed", self.mixed.is_some()),
+            ("digits", self.digits.is_some()),
+            ("spacing", self.spacing.is_some()),
+        ];
+        let set: Vec<&str> = options
+            .iter()
+            .filter(|(_, set)| *set)
+            .map(|(name, _)| *name)
+            .collect();
+        match set.as_slice() {
+            [] | [_] | ["fraction", "rules"] => Ok(()),
+            [first, second, ..] => Err(format!("`{first}` cannot be combined with `{second}`")),
+        }
+    }
+}
+
+/// Writes the plain numeral. Floats keep their own default reading of the fractional part
+/// (角 and 分), so they come here as they are instead of as a `Decimal`
+fn to_chinese(
+    value: impl NumberToChinese,
+    number_script: NumberScript,
+    count_method: ChineseCountMethod,
+    rules: Option<LocaleRules>,
+    fraction: Option<FractionFormat>,
+) -> Result<String, NumberToChineseError> {
+    match (rules, fraction) {
+        (Some(rules), fraction) => {
+            value.to_chinese_with_rules(number_script, count_method, rules, fraction)
+        }
+        (None, Some(fraction)) => {
+            value.to_chinese_with_fraction(number_script, count_method, fraction)
+        }
+        (None, None) => value.to_chinese(number_script, count_method),
+    }
 }
 
 pub fn number_to_text(input: &[u8]) -> Result<Vec<u8>, String> {
     let input: Input = from_reader(input).map_err(|e| e.to_string())?;
+    input.check_options()?;
+    let count_method = input
+        .count_method
+        .unwrap_or(ChineseCountMethod::TenThousand);
     let number_script = input
         .number_script
         .unwrap_or(NumberScript::SimplifiedChinese(ScriptStyle::Lower {
             circle_as_zero: false,
         }));
-    let count_method = input
-        .count_method
-        .unwrap_or(ChineseCountMethod::TenThousand);
-    let s = match input.value {
-        NumberInput::Integer(i) => i.to_chinese(number_script, count_method),
-        NumberInput::Float(f) => f.to_chinese(number_script, count_method),
+    let s = if let Some(kana) = input.kana {
+        input
+            .value
+            .into_decimal()?
+            .to_japanese_kana(count_method, kana)
+    } else if let Some(dialect) = input.quoc_ngu {
+        // Quốc ngữ has no script styles, but it is still only a Vietnamese reading
+        if !matches!(
+            input.number_script,
+            None | Some(NumberScript::Vietnamese(_))
+        ) {
+            return Err(NumberToChineseError::UnsupportedNumberScript.to_string());
+        }
+        input.value.into_decimal()?.to_quoc_ngu(dialect)
+    } else if let Some(system) = input.romanization {
+        input
+            .value
+            .into_decimal()?
+            .to_romanization(number_script, count_method, system)
+    } else if let Some(compact) = input.compact {
+        input
+            .value
+            .into_decimal()?
+            .to_compact(number_script, count_method, compact)
+    } else if let Some(mixed) = input.mixed {
+        input.value.into_decimal()?.to_mixed(number_script, mixed)
+    } else if let Some(digits) = input.digits {
+        input.value.into_decimal()?.to_digits(number_script, digits)
+    } else if let Some(spacing) = input.spacing {
+        input
+            .value
+            .into_decimal()?
+            .to_chinese_with_spacing(number_script, count_method, spacing)
+    } else {
+        let rules = input.rules.map(|rules| match rules {
+            RulesInput::Preset(preset) => preset.rules(),
+            RulesInput::Custom(rules) => rules,
+        });
+        match input.value {
+            NumberInput::Float(f) => {
+                to_chinese(f, number_script, count_method, rules, input.fraction)
+            }
+            value => to_chinese(
+                &value.into_decimal()?,
+                number_script,
+                count_method,
+                rules,
+                input.fraction,
+            ),
+        }
     }
     .map_err(|e| e.to_string())?;
     Ok(s.as_bytes().to_vec())
@@ -0,0 +1,30 @@
+use ciborium::from_reader;
+use conjak_numbers::{
+    ChineseCountMethod, NumberScript, NumberToOrdinal, OrdinalStyle, ScriptStyle,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Input {
+    value: i64,
+    number_script: Option<NumberScript>,
+    count_method: Option<ChineseCountMethod>,
+    style: Option<OrdinalStyle>,
+}
+
+pub fn ordinal_to_text(input: &[u8]) -> Result<Vec<u8>, String> {
+    let input: Input = from_reader(input).map_err(|e| e.to_string())?;
+    let number_script = input
+        .number_script
+        .unwrap_or(NumberScript::SimplifiedChinese(ScriptStyle::Lower {
+            circle_as_zero: false,
+        }));
+    let count_method = input
+        .count_method
+        .unwrap_or(ChineseCountMethod::TenThousand);
+    let s = input
+        .value
+        .to_ordinal(number_script, count_method, input.style.unwrap_or_default())
+        .map_err(|e| e.to_string())?;
+    Ok(s.as_bytes().to_vec())
+}
@@ -0,0 +1,63 @@
+use ciborium::from_reader;
+use conjak_numbers::{
+    ChineseCountMethod, DigitWidth, NumberScript, NumberToRatio, RatioStyle, ScriptStyle,
+    fraction_to_chinese, fraction_to_mixed,
+};
+use serde::Deserialize;
+
+use crate::number_to_text::NumberInput;
+
+#[derive(Deserialize)]
+struct RatioInput {
+    value: NumberInput,
+    number_script: Option<NumberScript>,
+    count_method: Option<ChineseCountMethod>,
+    style: Option<RatioStyle>,
+}
+
+#[derive(Deserialize)]
+struct FractionInput {
+    numerator: i64,
+    denominator: u64,
+    number_script: Option<NumberScript>,
+    count_method: Option<ChineseCountMethod>,
+    /// Write the numbers in Arabic digits, such as 3分の1
+    arabic: Option<DigitWidth>,
+}
+
+fn default_number_script() -> NumberScript {
+    NumberScript::SimplifiedChinese(ScriptStyle::Lower {
+        circle_as_zero: false,
+    })
+}
+
+pub fn ratio_to_text(input: &[u8]) -> Result<Vec<u8>, String> {
+    let input: RatioInput = from_reader(input).map_err(|e| e.to_string())?;
+    let number_script = input.number_script.unwrap_or_else(default_number_script);
+    let count_method = input
+        .count_method
+        .unwrap_or(ChineseCountMethod::TenThousand);
+    let style = input.style.unwrap_or_default();
+    let s = input
+        .value
+        .into_decimal()?
+        .to_ratio(number_script, count_method, style)
+        .map_err(|e| e.to_string())?;
+    Ok(s.as_bytes().to_vec())
+}
+
+pub fn fraction_to_text(input: &[u8]) -> Result<Vec<u8>, String> {
+    let input: FractionInput = from_reader(input).map_err(|e| e.to_string())?;
+    let number_script = input.number_script.unwrap_or_else(default_number_script);
+    let count_method = input
+        .count_method
+        .unwrap_or(ChineseCountMethod::TenThousand);
+    let numerator = input.numerator as i128;
+    let denominator = input.denominator as u128;
+    let s = match input.arabic {
+        Some(width) => fraction_to_mixed(number_script, width, numerator, denominator),
+        None => fraction_to_chinese(number_script, count_method, numerator, denominator),
+    }
+    .map_err(|e| e.to_string())?;
+    Ok(s.as_bytes().to_vec())
+}
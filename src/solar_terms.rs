@@ -0,0 +1,397 @@
+use std::ops::RangeInclusive;
+
+use chinese_lunisolar_calendar::SolarDate;
+use ciborium::{from_reader, into_writer};
+use conjak_numbers::{NumberScript, ScriptStyle};
+use serde::{Deserialize, Serialize};
+
+use crate::lunar::sun::{julian_day, solar_term_julian_day};
+
+/// The years the lunisolar calendar supports
+const MIN_YEAR: u16 = 1901;
+const MAX_YEAR: u16 = 2100;
+
+/// China Standard Time, which the Chinese calendar is reckoned in
+const DEFAULT_UTC_OFFSET: i32 = 8 * 60;
+/// From UTC−14:00 to UTC+14:00, in minutes
+const UTC_OFFSETS: RangeInclusive<i32> = -14 * 60..=14 * 60;
+
+const NAMES_SIMPLIFIED: [&str; 24] = [
+    "小寒", "大寒", "立春", "雨水", "惊蛰", "春分", "清明", "谷雨", "立夏", "小满", "芒种", "夏至",
+    "小暑", "大暑", "立秋", "处暑", "白露", "秋分", "寒露", "霜降", "立冬", "小雪", "大雪", "冬至",
+];
+const NAMES_TRADITIONAL: [&str; 24] = [
+    "小寒", "大寒", "立春", "雨水", "驚蟄", "春分", "清明", "穀雨", "立夏", "小滿", "芒種", "夏至",
+    "小暑", "大暑", "立秋", "處暑", "白露", "秋分", "寒露", "霜降", "立冬", "小雪", "大雪", "冬至",
+];
+const NAMES_JAPANESE: [&str; 24] = [
+    "小寒", "大寒", "立春", "雨水", "啓蟄", "春分", "清明", "穀雨", "立夏", "小満", "芒種", "夏至",
+    "小暑", "大暑", "立秋", "処暑", "白露", "秋分", "寒露", "霜降", "立冬", "小雪", "大雪", "冬至",
+];
+const NAMES_KOREAN: [&str; 24] = [
+    "소한", "대한", "입춘", "우수", "경칩", "춘분", "청명", "곡우", "입하", "소만", "망종", "하지",
+    "소서", "대서", "입추", "처서", "백로", "추분", "한로", "상강", "입동", "소설", "대설", "동지",
+];
+const NAMES_VIETNAMESE: [&str; 24] = [
+    "Tiểu hàn",
+    "Đại hàn",
+    "Lập xuân",
+    "Vũ thủy",
+    "Kinh trập",
+    "Xuân phân",
+    "Thanh minh",
+    "Cốc vũ",
+    "Lập hạ",
+    "Tiểu mãn",
+    "Mang chủng",
+    "Hạ chí",
+    "Tiểu thử",
+    "Đại thử",
+    "Lập thu",
+    "Xử thử",
+    "Bạch lộ",
+    "Thu phân",
+    "Hàn lộ",
+    "Sương giáng",
+    "Lập đông",
+    "Tiểu tuyết",
+    "Đại tuyết",
+    "Đông chí",
+];
+
+/// The name of a solar term, 0 for 小寒 and 23 for 冬至. Vietnamese uses Quốc ngữ
+fn term_to_str(number_script: NumberScript, index: usize) -> &'static str {
+    let names = match number_script {
+        NumberScript::SimplifiedChinese(_) => &NAMES_SIMPLIFIED,
+        NumberScript::TraditionalChinese(_) => &NAMES_TRADITIONAL,
+        NumberScript::Japanese(_) => &NAMES_JAPANESE,
+        NumberScript::Korean(_) => &NAMES_KOREAN,
+        NumberScript::Vietnamese(_) => &NAMES_VIETNAMESE,
+        // Scripts added to `conjak-numbers` later write the names in Traditional Chinese
+        _ => &NAMES_TRADITIONAL,
+    };
+    names[index]
+}
+
+/// The Gregorian date and time of a Julian day, as (year, month, day, hour, minute)
+fn from_julian_day(jd: f64) -> (i32, u8, u8, u8, u8) {
+    // Round to the minute first so that 23:59:40 does not become the next day at 24:00
+    let jd = (jd * 1440.0).round() / 1440.0 + 0.5;
+    let z = jd.floor() as i64;
+    let f = jd - z as f64;
+    let alpha = ((z as f64 - 1867216.25) / 36524.25).floor() as i64;
+    let a = z + 1 + alpha - alpha / 4;
+    let b = a + 1524;
+    let c = ((b as f64 - 122.1) / 365.25).floor() as i64;
+    let d = (365.25 * c as f64).floor() as i64;
+    let e = ((b - d) as f64 / 30.6001).floor() as i64;
+    let day = (b - d - (30.6001 * e as f64).floor() as i64) as u8;
+    let month = if e < 14 { e - 1 } else { e - 13 } as u8;
+    let year = if month > 2 { c - 4716 } else { c - 4715 } as i32;
+    let minutes = (f * 1440.0).round() as u32;
+    (year, month, day, (minutes / 60) as u8, (minutes % 60) as u8)
+}
+
+/// The Julian day, shifted to a time zone `utc_offset` minutes ahead of UTC, on which a solar term
+/// of a year begins, 0 for 小寒
+fn solar_term_local_julian_day(year: u16, index: usize, utc_offset: i32) -> f64 {
+    solar_term_julian_day(year, index) + utc_offset as f64 / 1440.0
+}
+
+#[derive(Serialize)]
+struct OutputSolarTerm {
+    /// 0 for 小寒 and 23 for 冬至, in the order they fall in a Gregorian year
+    index: u8,
+    /// The apparent longitude of the sun, a multiple of 15 such as 315 for 立春
+    longitude: u16,
+    name: &'static str,
+    /// The moment the term begins in the requested time zone, rounded to the minute
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+}
+
+impl OutputSolarTerm {
+    fn new(number_script: NumberScript, year: u16, index: usize, utc_offset: i32) -> Self {
+        let jd = solar_term_local_julian_day(year, index, utc_offset);
+        let (year, month, day, hour, minute) = from_julian_day(jd);
+        OutputSolarTerm {
+            index: index as u8,
+            longitude: ((285 + 15 * index) % 360) as u16,
+            name: term_to_str(number_script, index),
+            year,
+            month,
+            day,
+            hour,
+            minute,
+        }
+    }
+
+    fn date(&self) -> (i32, u8, u8) {
+        (self.year, self.month, self.day)
+    }
+}
+
+fn default_number_script() -> NumberScript {
+    NumberScript::SimplifiedChinese(ScriptStyle::Lower {
+        circle_as_zero: false,
+    })
+}
+
+fn check_year(year: u16) -> Result<(), String> {
+    if (MIN_YEAR..=MAX_YEAR).contains(&year) {
+        Ok(())
+    } else {
+        Err(format!(
+            "the year must be between {MIN_YEAR} and {MAX_YEAR}, got {year}"
+        ))
+    }
+}
+
+fn check_utc_offset(utc_offset: Option<i32>) -> Result<i32, String> {
+    let utc_offset = utc_offset.unwrap_or(DEFAULT_UTC_OFFSET);
+    if UTC_OFFSETS.contains(&utc_offset) {
+        Ok(utc_offset)
+    } else {
+        Err(format!(
+            "the UTC offset must be between {} and {} minutes, got {utc_offset}",
+            UTC_OFFSETS.start(),
+            UTC_OFFSETS.end()
+        ))
+    }
+}
+
+#[derive(Deserialize)]
+struct InputYear {
+    year: u16,
+    number_script: Option<NumberScript>,
+    /// Minutes ahead of UTC, 480 for China Standard Time
+    utc_offset: Option<i32>,
+}
+
+pub fn solar_terms(input: &[u8]) -> Result<Vec<u8>, String> {
+    let input: InputYear = from_reader(input).map_err(|e| e.to_string())?;
+    check_year(input.year)?;
+    let number_script = input.number_script.unwrap_or_else(default_number_script);
+    let utc_offset = check_utc_offset(input.utc_offset)?;
+    let output: Vec<OutputSolarTerm> = (0..24)
+        .map(|index| OutputSolarTerm::new(number_script, input.year, index, utc_offset))
+        .collect();
+    let mut output_bytes = Vec::new();
+    into_writer(&output, &mut output_bytes).map_err(|e| e.to_string())?;
+    Ok(output_bytes)
+}
+
+#[derive(Deserialize)]
+struct InputDate {
+    year: u16,
+    month: u8,
+    day: u8,
+    number_script: Option<NumberScript>,
+    /// Minutes ahead of UTC, 480 for China Standard Time
+    utc_offset: Option<i32>,
+}
+
+/// The solar term a date is in
+#[derive(Serialize)]
+struct OutputSolarTermOfDate {
+    term: OutputSolarTerm,
+    /// Days since the term began, 0 on the day it begins
+    days: u16,
+}
+
+pub fn solar_term_of_date(input: &[u8]) -> Result<Vec<u8>, String> {
+    let input: InputDate = from_reader(input).map_err(|e| e.to_string())?;
+    check_year(input.year)?;
+    SolarDate::from_ymd(input.year, input.month, input.day).map_err(|e| e.to_string())?;
+    let number_script = input.number_script.unwrap_or_else(default_number_script);
+    let utc_offset = check_utc_offset(input.utc_offset)?;
+    let date = (input.year as i32, input.month, input.day);
+    // Before 小寒, the date is still in the 冬至 of the previous year
+    let term = (0..24)
+        .rev()
+        .map(|index| OutputSolarTerm::new(number_script, input.year, index, utc_offset))
+        .find(|term| term.date() <= date)
+        .unwrap_or_else(|| OutputSolarTerm::new(number_script, input.year - 1, 23, utc_offset));
+    // The term began on or before the date, so this is a whole number of days from 0
+    let days = julian_day(date.0, date.1, date.2 as f64)
+        - julian_day(term.year, term.month, term.day as f64);
+    let output = OutputSolarTermOfDate {
+        term,
+        days: days as u16,
+    };
+    let mut output_bytes = Vec::new();
+    into_writer(&output, &mut output_bytes).map_err(|e| e.to_string())?;
+    Ok(output_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minutes from a published moment in China Standard Time to the computed one
+    fn minutes_off(year: u16, index: usize, published: (u8, u8, u8, u8)) -> f64 {
+        let (month, day, hour, minute) = published;
+        let published = julian_day(
+            year as i32,
+            month,
+            day as f64 + (hour as f64 * 60.0 + minute as f64) / 1440.0,
+        );
+        (solar_term_local_julian_day(year, index, DEFAULT_UTC_OFFSET) - published) * 1440.0
+    }
+
+    #[test]
+    fn published_moments() {
+        // Published to the minute, so a moment within seconds of the half minute may round the
+        // other way
+        for (year, index, published) in [
+            (1984, 2, (2, 4, 23, 19)),
+            (2021, 0, (1, 5, 11, 23)),
+            (2024, 2, (2, 4, 16, 27)),
+            (2024, 23, (12, 21, 17, 21)),
+            (2025, 2, (2, 3, 22, 10)),
+            (2025, 6, (4, 4, 20, 48)),
+            (2025, 18, (10, 8, 8, 41)),
+        ] {
+            let off = minutes_off(year, index, published);
+            assert!(off.abs() < 1.0, "{year} {index}: {off} minutes off");
+        }
+    }
+
+    #[test]
+    fn moments_to_the_minute() {
+        let term = OutputSolarTerm::new(default_number_script(), 2024, 2, DEFAULT_UTC_OFFSET);
+        assert_eq!("立春", term.name);
+        assert_eq!(
+            (2024, 2, 4, 16, 27),
+            (term.year, term.month, term.day, term.hour, term.minute)
+        );
+        let term = OutputSolarTerm::new(default_number_script(), 2025, 18, DEFAULT_UTC_OFFSET);
+        assert_eq!("寒露", term.name);
+        assert_eq!(
+            (2025, 10, 8, 8, 41),
+            (term.year, term.month, term.day, term.hour, term.minute)
+        );
+        // An hour later on the clocks of Japan Standard Time
+        let term = OutputSolarTerm::new(default_number_script(), 2025, 2, 9 * 60);
+        assert_eq!(
+            (2025, 2, 3, 23, 10),
+            (term.year, term.month, term.day, term.hour, term.minute)
+        );
+    }
+
+    #[derive(Serialize)]
+    struct DateInput {
+        year: u16,
+        month: u8,
+        day: u8,
+        utc_offset: Option<i32>,
+    }
+
+    #[derive(Serialize)]
+    struct YearInput {
+        year: u16,
+        utc_offset: Option<i32>,
+    }
+
+    #[derive(Deserialize)]
+    struct TermOutput {
+        name: String,
+        year: i32,
+        month: u8,
+        day: u8,
+    }
+
+    #[derive(Deserialize)]
+    struct TermOfDateOutput {
+        term: TermOutput,
+        days: u16,
+    }
+
+    /// The name and the first day of a term, and the days since it began
+    type TermOfDate = (String, (i32, u8, u8), u16);
+
+    fn term_of_date(
+        year: u16,
+        month: u8,
+        day: u8,
+        utc_offset: Option<i32>,
+    ) -> Result<TermOfDate, String> {
+        let mut input = Vec::new();
+        into_writer(
+            &DateInput {
+                year,
+                month,
+                day,
+                utc_offset,
+            },
+            &mut input,
+        )
+        .unwrap();
+        let output: TermOfDateOutput = from_reader(solar_term_of_date(&input)?.as_slice()).unwrap();
+        let term = output.term;
+        Ok((term.name, (term.year, term.month, term.day), output.days))
+    }
+
+    #[test]
+    fn term_of_a_date() {
+        // 立春 of 2024 began on February 4
+        assert_eq!(
+            Ok(("大寒".to_string(), (2024, 1, 20), 14)),
+            term_of_date(2024, 2, 3, None)
+        );
+        assert_eq!(
+            Ok(("立春".to_string(), (2024, 2, 4), 0)),
+            term_of_date(2024, 2, 4, None)
+        );
+        assert_eq!(
+            Ok(("立春".to_string(), (2024, 2, 4), 6)),
+            term_of_date(2024, 2, 10, None)
+        );
+        // Before 小寒, the date is still in the 冬至 of the previous year
+        assert_eq!(
+            Ok(("冬至".to_string(), (2024, 12, 21), 11)),
+            term_of_date(2025, 1, 1, None)
+        );
+        assert_eq!(
+            Ok(("小寒".to_string(), (2025, 1, 5), 0)),
+            term_of_date(2025, 1, 5, None)
+        );
+        // 立春 of 2025 began at 14:10 UTC, already February 4 at UTC+14
+        assert_eq!(
+            Ok(("大寒".to_string(), (2025, 1, 20), 14)),
+            term_of_date(2025, 2, 3, Some(14 * 60))
+        );
+        assert_eq!(
+            Ok(("立春".to_string(), (2025, 2, 3), 0)),
+            term_of_date(2025, 2, 3, Some(-14 * 60))
+        );
+    }
+
+    #[test]
+    fn utc_offset_out_of_range() {
+        assert_eq!(
+            Err("the UTC offset must be between -840 and 840 minutes, got 841".to_string()),
+            term_of_date(2025, 2, 3, Some(841))
+        );
+        assert_eq!(
+            Err("the UTC offset must be between -840 and 840 minutes, got -841".to_string()),
+            term_of_date(2025, 2, 3, Some(-841))
+        );
+        let mut input = Vec::new();
+        into_writer(
+            &YearInput {
+                year: 2025,
+                utc_offset: Some(-841),
+            },
+            &mut input,
+        )
+        .unwrap();
+        assert_eq!(
+            Err("the UTC offset must be between -840 and 840 minutes, got -841".to_string()),
+            solar_terms(&input)
+        );
+    }
+}
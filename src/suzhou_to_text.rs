@@ -0,0 +1,44 @@
+use ciborium::{from_reader, into_writer};
+use conjak_numbers::{NumberScript, NumberToSuzhou, ScriptStyle, SuzhouNumeral};
+use serde::{Deserialize, Serialize};
+
+use crate::number_to_text::NumberInput;
+
+#[derive(Deserialize)]
+struct Input {
+    value: NumberInput,
+    number_script: Option<NumberScript>,
+}
+
+#[derive(Serialize)]
+struct Output {
+    digits: String,
+    magnitude: String,
+}
+
+impl From<SuzhouNumeral> for Output {
+    fn from(numeral: SuzhouNumeral) -> Self {
+        Output {
+            digits: numeral.digits,
+            magnitude: numeral.magnitude,
+        }
+    }
+}
+
+pub fn suzhou_to_text(input: &[u8]) -> Result<Vec<u8>, String> {
+    let input: Input = from_reader(input).map_err(|e| e.to_string())?;
+    let number_script = input
+        .number_script
+        .unwrap_or(NumberScript::TraditionalChinese(ScriptStyle::Lower {
+            circle_as_zero: false,
+        }));
+    let numeral = input
+        .value
+        .into_decimal()?
+        .to_suzhou(number_script)
+        .map_err(|e| e.to_string())?;
+    let output: Output = numeral.into();
+    let mut output_bytes = Vec::new();
+    into_writer(&output, &mut output_bytes).map_err(|e| e.to_string())?;
+    Ok(output_bytes)
+}
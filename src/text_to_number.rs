@@ -0,0 +1,106 @@
+use ciborium::{from_reader, into_writer};
+use conjak_numbers::{
+    ChineseCountMethod, ChineseToNumber, ChineseToNumberError, Decimal, NumberScript,
+    detect_number_script, is_naive_chinese_number,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Auto {
+    Auto,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumberScriptInput {
+    Auto(Auto),
+    Script(NumberScript),
+}
+
+#[derive(Deserialize)]
+struct Input {
+    text: String,
+    number_script: Option<NumberScriptInput>,
+    count_method: Option<ChineseCountMethod>,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum NumberOutput {
+    Integer(i64),
+    Float(f64),
+    /// The exact decimal text, for values that neither i64 nor f64 can hold without losing digits
+    Decimal(String),
+}
+
+#[derive(Serialize)]
+struct Output {
+    value: NumberOutput,
+    number_script: NumberScript,
+}
+
+impl From<Decimal> for NumberOutput {
+    fn from(value: Decimal) -> Self {
+        if value.fraction().is_empty() {
+            // i128 so that the magnitude of i64::MIN still fits before the sign is applied
+            let integer = i128::try_from(value.integer())
+                .ok()
+                .and_then(|i| i64::try_from(if value.is_negative() { -i } else { i }).ok());
+            if let Some(i) = integer {
+                return NumberOutput::Integer(i);
+            }
+        }
+        // a float is only used when it reads back as exactly the same digits
+        match value.to_string().parse::<f64>() {
+            Ok(f)
+                if !value.fraction().is_empty() && Decimal::from_f64(f) == Some(value.clone()) =>
+            {
+                NumberOutput::Float(f)
+            }
+            _ => NumberOutput::Decimal(value.to_string()),
+        }
+    }
+}
+
+pub fn text_to_number(input: &[u8]) -> Result<Vec<u8>, String> {
+    let input: Input = from_reader(input).map_err(|e| e.to_string())?;
+    let text = input.text.as_str();
+    let count_method = input
+        .count_method
+        .unwrap_or(ChineseCountMethod::TenThousand);
+    let naive = is_naive_chinese_number(text);
+    let (value, number_script): (Decimal, NumberScript) = match input.number_script {
+        Some(NumberScriptInput::Script(number_script)) => {
+            let value = if naive {
+                text.to_number_naive_with_script(number_script)
+            } else {
+                text.to_number_with_script(number_script, count_method)
+            };
+            let value = value.map_err(|e| match e {
+                ChineseToNumberError::ChineseNumberIncorrect { .. } => {
+                    format!("\"{text}\" is not a number written in {number_script:?}: {e}")
+                }
+                _ => e.to_string(),
+            })?;
+            (value, number_script)
+        }
+        _ => {
+            let number_script = detect_number_script(text)
+                .ok_or_else(|| format!("cannot detect the number script of \"{text}\""))?;
+            let value = if naive {
+                text.to_number_naive()
+            } else {
+                text.to_number(count_method)
+            };
+            (value.map_err(|e| e.to_string())?, number_script)
+        }
+    };
+    let output = Output {
+        value: value.into(),
+        number_script,
+    };
+    let mut output_bytes = Vec::new();
+    into_writer(&output, &mut output_bytes).map_err(|e| e.to_string())?;
+    Ok(output_bytes)
+}